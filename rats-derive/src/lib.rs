@@ -0,0 +1,543 @@
+//! Derive macros for the `rats` kernel type classes. Use them through the
+//! `derive` feature of `rats` rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Field, Fields, Generics, Ident, Type};
+
+/// Derives `Semigroup` by combining every field through its own instance.
+///
+/// A field can pick a wrapper from `rats::kernel::wrappers` instead, with
+/// `#[semigroup(sum)]`, `product`, `min`, `max`, `first` or `last`.
+///
+/// For enums, two values of the same variant combine their fields, and for
+/// different variants the one declared later wins.
+#[proc_macro_derive(Semigroup, attributes(semigroup))]
+pub fn derive_semigroup(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_semigroup(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `Monoid` with every field set to its own `empty`, honouring the
+/// same `#[semigroup(..)]` field attributes. The type must also implement
+/// `Semigroup`, usually through the derive of the same name.
+///
+/// For enums the identity is the first variant with empty fields.
+#[proc_macro_derive(Monoid, attributes(semigroup))]
+pub fn derive_monoid(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_monoid(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `HasPartial`, generating a `Partial{Name}` companion struct in
+/// which every field is optional. Combining two companions keeps, field by
+/// field, the value of the later one when it is set.
+///
+/// Fields are required unless their type is an `Option` or they are marked
+/// `#[partial(default)]`, in which case a missing value falls back to
+/// `Default`. Fields marked `#[partial(nested)]` are partials themselves and
+/// are combined and finalized recursively. Derives for the companion can be
+/// requested with `#[partial(derive(..))]` on the struct.
+#[proc_macro_derive(Partial, attributes(partial))]
+pub fn derive_partial(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_partial(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Strategy {
+    Combine,
+    Sum,
+    Product,
+    Min,
+    Max,
+    First,
+    Last,
+}
+
+impl Strategy {
+    fn of(field: &Field) -> syn::Result<Strategy> {
+        let mut strategy = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("semigroup"))
+        {
+            attr.parse_nested_meta(|meta| {
+                let picked = match meta.path.get_ident().map(Ident::to_string).as_deref() {
+                    Some("sum") => Strategy::Sum,
+                    Some("product") => Strategy::Product,
+                    Some("min") => Strategy::Min,
+                    Some("max") => Strategy::Max,
+                    Some("first") => Strategy::First,
+                    Some("last") => Strategy::Last,
+                    _ => {
+                        return Err(meta.error(
+                            "unknown semigroup strategy, expected one of \
+                             `sum`, `product`, `min`, `max`, `first` or `last`",
+                        ))
+                    }
+                };
+                if strategy.replace(picked).is_some() {
+                    return Err(meta.error("a field can only have one semigroup strategy"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(strategy.unwrap_or(Strategy::Combine))
+    }
+
+    fn wrapper(self) -> Option<Ident> {
+        let name = match self {
+            Strategy::Combine => return None,
+            Strategy::Sum => "Sum",
+            Strategy::Product => "Product",
+            Strategy::Min => "Min",
+            Strategy::Max => "Max",
+            Strategy::First => "First",
+            Strategy::Last => "Last",
+        };
+        Some(format_ident!("{}", name))
+    }
+
+    /// The type whose instance is used for a field of type `ty`.
+    fn instance_type(self, ty: &Type) -> TokenStream2 {
+        match self.wrapper() {
+            None => quote!(#ty),
+            Some(wrapper) => quote!(::rats::kernel::wrappers::#wrapper<#ty>),
+        }
+    }
+
+    fn combine(self, field: &Field, a: &TokenStream2, b: &TokenStream2) -> TokenStream2 {
+        let ty = &field.ty;
+        let instance = self.instance_type(ty);
+        let span = ty.span();
+        match self.wrapper() {
+            None => quote_spanned! {span=>
+                <#instance as ::rats::kernel::semigroup::Semigroup>::combine(#a, #b)
+            },
+            Some(wrapper) => quote_spanned! {span=>
+                <#instance as ::rats::kernel::semigroup::Semigroup>::combine(
+                    ::rats::kernel::wrappers::#wrapper(#a),
+                    ::rats::kernel::wrappers::#wrapper(#b),
+                ).0
+            },
+        }
+    }
+
+    fn empty(self, field: &Field) -> TokenStream2 {
+        let instance = self.instance_type(&field.ty);
+        let span = field.ty.span();
+        match self.wrapper() {
+            None => quote_spanned! {span=>
+                <#instance as ::rats::kernel::monoid::Monoid>::empty()
+            },
+            Some(_) => quote_spanned! {span=>
+                <#instance as ::rats::kernel::monoid::Monoid>::empty().0
+            },
+        }
+    }
+}
+
+/// Adds `bound` for the instance type of every field, but only on generic
+/// types; for concrete ones the error in the generated body reads better.
+fn bounded_generics<'a>(
+    generics: &Generics,
+    fields: impl Iterator<Item = &'a Field>,
+    bound: TokenStream2,
+) -> syn::Result<Generics> {
+    let mut generics = generics.clone();
+    if generics.type_params().next().is_none() {
+        return Ok(generics);
+    }
+    let mut predicates = Vec::new();
+    for field in fields {
+        let instance = Strategy::of(field)?.instance_type(&field.ty);
+        predicates.push(syn::parse2::<syn::WherePredicate>(
+            quote!(#instance: #bound),
+        )?);
+    }
+    generics.make_where_clause().predicates.extend(predicates);
+    Ok(generics)
+}
+
+fn field_names(fields: &Fields, prefix: &str) -> Vec<Ident> {
+    (0..fields.len())
+        .map(|i| format_ident!("__{}_{}", prefix, i))
+        .collect()
+}
+
+/// Builds `Path { field: expr, .. }` or `Path(expr, ..)` from one expression
+/// per field.
+fn construct(path: TokenStream2, fields: &Fields, values: Vec<TokenStream2>) -> TokenStream2 {
+    match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|f| &f.ident);
+            quote!(#path { #(#names: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+        Fields::Unit => path,
+    }
+}
+
+/// A pattern binding every field of `path` to `__{prefix}_{index}`.
+fn destructure(path: TokenStream2, fields: &Fields, prefix: &str) -> TokenStream2 {
+    let bindings = field_names(fields, prefix);
+    match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|f| &f.ident);
+            quote!(#path { #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#bindings),*)),
+        Fields::Unit => path,
+    }
+}
+
+fn combine_fields(fields: &Fields) -> syn::Result<Vec<TokenStream2>> {
+    let a = field_names(fields, "a");
+    let b = field_names(fields, "b");
+    fields
+        .iter()
+        .zip(a.iter().zip(&b))
+        .map(|(field, (a, b))| Ok(Strategy::of(field)?.combine(field, &quote!(#a), &quote!(#b))))
+        .collect()
+}
+
+fn empty_fields(fields: &Fields) -> syn::Result<Vec<TokenStream2>> {
+    fields
+        .iter()
+        .map(|field| Ok(Strategy::of(field)?.empty(field)))
+        .collect()
+}
+
+fn expand_semigroup(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern_a = destructure(quote!(Self), &data.fields, "a");
+            let pattern_b = destructure(quote!(Self), &data.fields, "b");
+            let combined = construct(quote!(Self), &data.fields, combine_fields(&data.fields)?);
+            quote! {
+                let #pattern_a = self;
+                let #pattern_b = b;
+                #combined
+            }
+        }
+        Data::Enum(data) => {
+            let mut same_variant = Vec::new();
+            let mut indices = Vec::new();
+            for (index, variant) in data.variants.iter().enumerate() {
+                let ident = &variant.ident;
+                let path = quote!(Self::#ident);
+                let pattern_a = destructure(path.clone(), &variant.fields, "a");
+                let pattern_b = destructure(path.clone(), &variant.fields, "b");
+                let combined = construct(
+                    path.clone(),
+                    &variant.fields,
+                    combine_fields(&variant.fields)?,
+                );
+                same_variant.push(quote!((#pattern_a, #pattern_b) => #combined,));
+                let wildcard = match &variant.fields {
+                    Fields::Named(_) => quote!(#path { .. }),
+                    Fields::Unnamed(_) => quote!(#path(..)),
+                    Fields::Unit => path,
+                };
+                indices.push(quote!(#wildcard => #index,));
+            }
+            if data.variants.is_empty() {
+                quote!(match self {})
+            } else {
+                quote! {
+                    match (self, b) {
+                        #(#same_variant)*
+                        (a, b) => {
+                            let index = |value: &Self| match value { #(#indices)* };
+                            if index(&a) > index(&b) { a } else { b }
+                        }
+                    }
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Semigroup cannot be derived for unions",
+            ))
+        }
+    };
+    let fields = all_fields(&input.data);
+    let generics = bounded_generics(
+        &input.generics,
+        fields.into_iter(),
+        quote!(::rats::kernel::semigroup::Semigroup),
+    )?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::rats::kernel::semigroup::Semigroup for #name #ty_generics #where_clause {
+            fn combine(self, b: Self) -> Self {
+                #body
+            }
+        }
+    })
+}
+
+fn expand_monoid(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (body, fields) = match &input.data {
+        Data::Struct(data) => (
+            construct(quote!(Self), &data.fields, empty_fields(&data.fields)?),
+            data.fields.iter().collect::<Vec<_>>(),
+        ),
+        Data::Enum(data) => {
+            let first = data.variants.first().ok_or_else(|| {
+                Error::new_spanned(&input.ident, "Monoid cannot be derived for an empty enum")
+            })?;
+            let ident = &first.ident;
+            (
+                construct(
+                    quote!(Self::#ident),
+                    &first.fields,
+                    empty_fields(&first.fields)?,
+                ),
+                first.fields.iter().collect(),
+            )
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Monoid cannot be derived for unions",
+            ))
+        }
+    };
+    let generics = bounded_generics(
+        &input.generics,
+        fields.into_iter(),
+        quote!(::rats::kernel::monoid::Monoid),
+    )?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::rats::kernel::monoid::Monoid for #name #ty_generics #where_clause {
+            fn empty() -> Self {
+                #body
+            }
+        }
+    })
+}
+
+fn all_fields(data: &Data) -> Vec<&Field> {
+    match data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data.variants.iter().flat_map(|v| v.fields.iter()).collect(),
+        Data::Union(_) => Vec::new(),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Layer {
+    Required,
+    Optional,
+    Default,
+    Nested,
+}
+
+impl Layer {
+    fn of(field: &Field) -> syn::Result<Layer> {
+        let mut layer = None;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("partial")) {
+            attr.parse_nested_meta(|meta| {
+                let picked = match meta.path.get_ident().map(Ident::to_string).as_deref() {
+                    Some("default") => Layer::Default,
+                    Some("nested") => Layer::Nested,
+                    _ => {
+                        return Err(
+                            meta.error("unknown partial option, expected `default` or `nested`")
+                        )
+                    }
+                };
+                if layer.replace(picked).is_some() {
+                    return Err(meta.error("a field can only have one partial option"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(layer.unwrap_or_else(|| {
+            if is_option(&field.ty) {
+                Layer::Optional
+            } else {
+                Layer::Required
+            }
+        }))
+    }
+}
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// The derives listed in `#[partial(derive(..))]` on the struct.
+fn companion_derives(input: &DeriveInput) -> syn::Result<Vec<syn::Path>> {
+    let mut derives = Vec::new();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("partial")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("derive") {
+                return Err(meta.error("unknown partial option, expected `derive(..)`"));
+            }
+            meta.parse_nested_meta(|derive| {
+                derives.push(derive.path);
+                Ok(())
+            })
+        })?;
+    }
+    Ok(derives)
+}
+
+fn expand_partial(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    name,
+                    "Partial can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                name,
+                "Partial can only be derived for structs with named fields",
+            ))
+        }
+    };
+    let vis = &input.vis;
+    let partial = format_ident!("Partial{}", name);
+    let derives = companion_derives(&input)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut declarations = Vec::new();
+    let mut combines = Vec::new();
+    let mut empties = Vec::new();
+    let mut finalizes = Vec::new();
+    let mut required = Vec::new();
+    let mut names = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let label = ident.to_string();
+        let ty = &field.ty;
+        let span = ty.span();
+        let layer = Layer::of(field)?;
+        let declared = match layer {
+            Layer::Optional => quote!(#ty),
+            Layer::Required | Layer::Default => quote!(::std::option::Option<#ty>),
+            Layer::Nested => quote!(::rats::kernel::partial::Partial<#ty>),
+        };
+        declarations.push(quote!(#vis #ident: #declared));
+        match layer {
+            Layer::Nested => {
+                combines.push(quote_spanned! {span=>
+                    #ident: ::rats::kernel::semigroup::Semigroup::combine(self.#ident, b.#ident)
+                });
+                empties.push(quote_spanned! {span=>
+                    #ident: ::rats::kernel::monoid::Monoid::empty()
+                });
+            }
+            _ => {
+                combines.push(quote!(#ident: b.#ident.or(self.#ident)));
+                empties.push(quote!(#ident: ::std::option::Option::None));
+            }
+        }
+        finalizes.push(match layer {
+            Layer::Required => quote! {
+                let #ident = self.#ident;
+                if #ident.is_none() {
+                    missing.push(::rats::kernel::partial::MissingField::at(path, #label));
+                }
+            },
+            Layer::Optional => quote!(let #ident = ::std::option::Option::Some(self.#ident);),
+            Layer::Default => quote_spanned! {span=>
+                let #ident = ::std::option::Option::Some(self.#ident.unwrap_or_default());
+            },
+            Layer::Nested => quote_spanned! {span=>
+                let #ident = ::rats::kernel::partial::Finalize::finalize_at(
+                    self.#ident,
+                    &::rats::kernel::partial::join(path, #label),
+                    missing,
+                );
+            },
+        });
+        required.push(quote!(::std::option::Option::Some(#ident)));
+        names.push(ident);
+    }
+
+    let derive_attr = if derives.is_empty() {
+        quote!()
+    } else {
+        quote!(#[derive(#(#derives),*)])
+    };
+    let struct_generics = &input.generics;
+    Ok(quote! {
+        #derive_attr
+        #vis struct #partial #struct_generics #where_clause {
+            #(#declarations,)*
+        }
+
+        impl #impl_generics ::rats::kernel::semigroup::Semigroup for #partial #ty_generics #where_clause {
+            fn combine(self, b: Self) -> Self {
+                #partial {
+                    #(#combines,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::rats::kernel::monoid::Monoid for #partial #ty_generics #where_clause {
+            fn empty() -> Self {
+                #partial {
+                    #(#empties,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::std::default::Default for #partial #ty_generics #where_clause {
+            fn default() -> Self {
+                ::rats::kernel::monoid::Monoid::empty()
+            }
+        }
+
+        impl #impl_generics ::rats::kernel::partial::Finalize for #partial #ty_generics #where_clause {
+            type Target = #name #ty_generics;
+
+            fn finalize_at(
+                self,
+                path: &str,
+                missing: &mut ::std::vec::Vec<::rats::kernel::partial::MissingField>,
+            ) -> ::std::option::Option<Self::Target> {
+                #(#finalizes)*
+                match (#(#names,)*) {
+                    (#(#required,)*) => ::std::option::Option::Some(#name { #(#names,)* }),
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+
+        impl #impl_generics ::rats::kernel::partial::HasPartial for #name #ty_generics #where_clause {
+            type Partial = #partial #ty_generics;
+        }
+    })
+}
@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use rats::kernel::prelude::*;
+
+#[derive(Semigroup, Monoid, Clone, Debug, PartialEq)]
+struct Stats {
+    names: Vec<String>,
+    seen: HashSet<u32>,
+    #[semigroup(sum)]
+    count: u64,
+    #[semigroup(max)]
+    peak: i32,
+    #[semigroup(min)]
+    low: i32,
+    #[semigroup(product)]
+    scale: u32,
+}
+
+#[derive(Semigroup, Clone, Debug, PartialEq)]
+struct Window {
+    #[semigroup(first)]
+    opened: String,
+    #[semigroup(last)]
+    closed: String,
+    #[semigroup(max)]
+    peak: f64,
+}
+
+#[derive(Semigroup, Monoid, Clone, Debug, PartialEq)]
+struct Pair<A, B>(A, #[semigroup(sum)] B);
+
+#[derive(Semigroup, Monoid, Clone, Debug, PartialEq)]
+struct Marker;
+
+#[derive(Semigroup, Monoid, Clone, Debug, PartialEq)]
+enum Level {
+    Ok { checks: u32 },
+    Warn(Vec<String>),
+    Fatal,
+}
+
+fn stats(name: &str, value: i32) -> Stats {
+    Stats {
+        names: vec![name.to_string()],
+        seen: HashSet::from([value as u32]),
+        count: 1,
+        peak: value,
+        low: value,
+        scale: 2,
+    }
+}
+
+#[test]
+fn struct_fields_combine_through_their_instances() {
+    let combined = stats("a", 3).combine(stats("b", 7));
+    assert_eq!(combined.names, vec!["a", "b"]);
+    assert_eq!(combined.seen, HashSet::from([3, 7]));
+    assert_eq!(combined.count, 2);
+    assert_eq!(combined.peak, 7);
+    assert_eq!(combined.low, 3);
+    assert_eq!(combined.scale, 4);
+}
+
+#[test]
+fn struct_monoid_is_the_identity() {
+    assert_eq!(Stats::empty().combine(stats("a", 3)), stats("a", 3));
+    assert_eq!(stats("a", 3).combine(Stats::empty()), stats("a", 3));
+    assert_eq!(Stats::empty().peak, i32::MIN);
+    assert_eq!(Stats::empty().low, i32::MAX);
+}
+
+#[test]
+fn first_and_last_strategies() {
+    let morning = Window {
+        opened: "08:00".to_string(),
+        closed: "12:00".to_string(),
+        peak: 1.5,
+    };
+    let evening = Window {
+        opened: "18:00".to_string(),
+        closed: "22:00".to_string(),
+        peak: 0.5,
+    };
+    let day = morning.combine(evening);
+    assert_eq!(day.opened, "08:00");
+    assert_eq!(day.closed, "22:00");
+    assert_eq!(day.peak, 1.5);
+}
+
+#[test]
+fn generic_tuple_structs() {
+    let combined = Pair("a".to_string(), 1u8).combine(Pair("b".to_string(), 2));
+    assert_eq!(combined, Pair("ab".to_string(), 3));
+    assert_eq!(Pair::<String, u8>::empty(), Pair(String::new(), 0));
+    assert_eq!(Marker.combine(Marker), Marker::empty());
+}
+
+#[test]
+fn enum_variants_combine_or_the_later_one_wins() {
+    let ok = Level::Ok { checks: 2 };
+    let warn = |w: &str| Level::Warn(vec![w.to_string()]);
+    assert_eq!(ok.clone().combine(ok.clone()), Level::Ok { checks: 4 });
+    assert_eq!(
+        warn("a").combine(warn("b")),
+        Level::Warn(vec!["a".into(), "b".into()])
+    );
+    assert_eq!(ok.clone().combine(warn("a")), warn("a"));
+    assert_eq!(warn("a").combine(ok.clone()), warn("a"));
+    assert_eq!(warn("a").combine(Level::Fatal), Level::Fatal);
+    assert_eq!(Level::empty(), Level::Ok { checks: 0 });
+}
+
+#[test]
+fn enum_combine_is_associative() {
+    let values = [
+        Level::Ok { checks: 1 },
+        Level::Ok { checks: 5 },
+        Level::Warn(vec!["x".into()]),
+        Level::Warn(vec!["y".into()]),
+        Level::Fatal,
+    ];
+    for a in &values {
+        for b in &values {
+            for c in &values {
+                let left = a.clone().combine(b.clone()).combine(c.clone());
+                let right = a.clone().combine(b.clone().combine(c.clone()));
+                assert_eq!(left, right);
+            }
+        }
+    }
+}
+
+#[test]
+fn compile_failures() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
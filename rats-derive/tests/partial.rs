@@ -0,0 +1,124 @@
+use rats::kernel::prelude::*;
+
+#[derive(Partial, Debug, PartialEq)]
+#[partial(derive(Clone, Debug, PartialEq))]
+struct Config {
+    name: String,
+    port: u16,
+    #[partial(default)]
+    verbose: bool,
+    log_file: Option<String>,
+    #[partial(nested)]
+    database: Database,
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partial(derive(Clone, Debug, PartialEq))]
+struct Database {
+    url: String,
+    #[partial(default)]
+    pool_size: u32,
+}
+
+fn defaults() -> Partial<Config> {
+    PartialConfig {
+        port: Some(8080),
+        database: PartialDatabase {
+            pool_size: Some(4),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn later_layers_win_field_by_field() {
+    let file = PartialConfig {
+        name: Some("file".into()),
+        port: Some(9000),
+        database: PartialDatabase {
+            url: Some("postgres://file".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let cli = PartialConfig {
+        name: Some("cli".into()),
+        verbose: Some(true),
+        ..Default::default()
+    };
+    let config = defaults().combine(file).combine(cli).finalize();
+    assert_eq!(
+        config,
+        Ok(Config {
+            name: "cli".into(),
+            port: 9000,
+            verbose: true,
+            log_file: None,
+            database: Database {
+                url: "postgres://file".into(),
+                pool_size: 4,
+            },
+        })
+    );
+}
+
+#[test]
+fn unset_fields_do_not_override() {
+    let layer = PartialConfig {
+        name: Some("a".into()),
+        ..Default::default()
+    };
+    assert_eq!(layer.clone().combine(PartialConfig::empty()), layer);
+    assert_eq!(PartialConfig::empty().combine(layer.clone()), layer);
+}
+
+#[test]
+fn finalize_reports_every_missing_field() {
+    let missing = defaults().finalize().unwrap_err();
+    let paths: Vec<_> = missing.iter().map(|m| m.path.as_str()).collect();
+    assert_eq!(paths, ["name", "database.url"]);
+}
+
+#[test]
+fn optional_and_default_fields_are_not_required() {
+    let layer = PartialDatabase {
+        url: Some("sqlite://".into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        layer.finalize(),
+        Ok(Database {
+            url: "sqlite://".into(),
+            pool_size: 0,
+        })
+    );
+}
+
+#[test]
+fn combine_is_associative() {
+    let layers = [
+        PartialDatabase::empty(),
+        PartialDatabase {
+            url: Some("a".into()),
+            pool_size: None,
+        },
+        PartialDatabase {
+            url: Some("b".into()),
+            pool_size: Some(2),
+        },
+        PartialDatabase {
+            url: None,
+            pool_size: Some(3),
+        },
+    ];
+    for a in &layers {
+        for b in &layers {
+            for c in &layers {
+                let left = a.clone().combine(b.clone()).combine(c.clone());
+                let right = a.clone().combine(b.clone().combine(c.clone()));
+                assert_eq!(left, right);
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use rats::kernel::prelude::*;
+
+#[derive(Semigroup, Monoid)]
+struct Session {
+    #[semigroup(first)]
+    started: String,
+}
+
+fn main() {}
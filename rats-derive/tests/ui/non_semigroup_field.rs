@@ -0,0 +1,11 @@
+use rats::kernel::prelude::*;
+
+struct Opaque;
+
+#[derive(Semigroup)]
+struct Stats {
+    count: u64,
+    opaque: Opaque,
+}
+
+fn main() {}
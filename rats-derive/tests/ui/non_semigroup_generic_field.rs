@@ -0,0 +1,12 @@
+use rats::kernel::prelude::*;
+
+struct Opaque;
+
+#[derive(Semigroup)]
+struct Wrapper<A>(A);
+
+fn combine_opaque(a: Wrapper<Opaque>, b: Wrapper<Opaque>) -> Wrapper<Opaque> {
+    a.combine(b)
+}
+
+fn main() {}
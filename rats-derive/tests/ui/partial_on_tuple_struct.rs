@@ -0,0 +1,6 @@
+use rats::kernel::prelude::*;
+
+#[derive(Partial)]
+struct Port(u16);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rats::kernel::prelude::*;
+
+#[derive(Semigroup)]
+struct Stats {
+    #[semigroup(average)]
+    latency: u64,
+}
+
+fn main() {}
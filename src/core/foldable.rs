@@ -1,4 +1,11 @@
-use crate::kernel::prelude::Monoid;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::rc::Rc;
+
+use super::prelude::{Monad, MonoidK, SemigroupK, TraverseApplicative};
+use crate::data::either::{Either, Left, Right};
+use crate::data::eval::Eval;
+use crate::kernel::prelude::{Monoid, MonoidInstance, Semigroup};
 
 pub trait Foldable {
     type Inner;
@@ -6,6 +13,268 @@ pub trait Foldable {
 
     fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B;
     fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B;
+
+    /// A right fold that only evaluates as much as `f` demands: returning
+    /// without forcing its `Eval` argument stops the fold there. The result
+    /// is trampolined, so deep folds don't overflow the stack.
+    fn fold_right_lazy<B: Clone + 'static>(
+        self,
+        end: Eval<B>,
+        f: impl Fn(Self::Inner, Eval<B>) -> Eval<B> + 'static,
+    ) -> Eval<B>
+    where
+        Self: Sized,
+        Self::Inner: 'static,
+    {
+        lazy_fold_right(self.fold_to_vec().into_iter(), end, Rc::new(f))
+    }
+
+    /// Folds left until `f` returns an error. The default stops calling `f`
+    /// but still walks the rest of the elements; implementations backed by
+    /// an iterator override it to stop right away.
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        mut f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E>
+    where
+        Self: Sized,
+    {
+        self.fold_left(Ok(start), |acc, a| acc.and_then(|b| f(b, a)))
+    }
+
+    fn find(self, mut p: impl FnMut(&Self::Inner) -> bool) -> Option<Self::Inner>
+    where
+        Self: Sized,
+    {
+        self.try_fold_left((), |(), a| if p(&a) { Err(a) } else { Ok(()) })
+            .err()
+    }
+
+    fn collect_first<B>(self, mut f: impl FnMut(Self::Inner) -> Option<B>) -> Option<B>
+    where
+        Self: Sized,
+    {
+        self.try_fold_left((), |(), a| f(a).map_or(Ok(()), Err))
+            .err()
+    }
+
+    fn exists(self, mut p: impl FnMut(&Self::Inner) -> bool) -> bool
+    where
+        Self: Sized,
+    {
+        self.find(|a| p(a)).is_some()
+    }
+
+    fn forall(self, mut p: impl FnMut(&Self::Inner) -> bool) -> bool
+    where
+        Self: Sized,
+    {
+        !self.exists(|a| !p(a))
+    }
+
+    fn size(self) -> usize
+    where
+        Self: Sized,
+    {
+        self.fold_left(0, |n, _| n + 1)
+    }
+
+    /// `is_empty` for any `Foldable`. The `fold_` prefix keeps this and
+    /// `fold_to_vec`/`fold_get` from shadowing the borrowing `Vec`/slice
+    /// methods of the same name with consuming versions.
+    fn fold_is_empty(self) -> bool
+    where
+        Self: Sized,
+    {
+        !self.exists(|_| true)
+    }
+
+    fn fold_to_vec(self) -> Vec<Self::Inner>
+    where
+        Self: Sized,
+    {
+        self.fold_left(Vec::new(), |mut acc, a| {
+            acc.push(a);
+            acc
+        })
+    }
+
+    /// The element at `index`, counting from the left.
+    fn fold_get(self, index: usize) -> Option<Self::Inner>
+    where
+        Self: Sized,
+    {
+        self.try_fold_left(0, |i, a| if i == index { Err(a) } else { Ok(i + 1) })
+            .err()
+    }
+
+    fn reduce_left_option(
+        self,
+        mut f: impl FnMut(Self::Inner, Self::Inner) -> Self::Inner,
+    ) -> Option<Self::Inner>
+    where
+        Self: Sized,
+    {
+        self.fold_left(None, |acc, a| match acc {
+            None => Some(a),
+            Some(acc) => Some(f(acc, a)),
+        })
+    }
+
+    /// The first of the smallest elements, like `Iterator::min`.
+    fn minimum_option(self) -> Option<Self::Inner>
+    where
+        Self: Sized,
+        Self::Inner: Ord,
+    {
+        self.reduce_left_option(|a, b| if b < a { b } else { a })
+    }
+
+    /// The last of the largest elements, like `Iterator::max`.
+    fn maximum_option(self) -> Option<Self::Inner>
+    where
+        Self: Sized,
+        Self::Inner: Ord,
+    {
+        self.reduce_left_option(|a, b| if b >= a { b } else { a })
+    }
+
+    fn minimum_option_by_key<K: Ord>(
+        self,
+        mut key: impl FnMut(&Self::Inner) -> K,
+    ) -> Option<Self::Inner>
+    where
+        Self: Sized,
+    {
+        self.fold_left(None, |acc: Option<(K, Self::Inner)>, a| {
+            let k = key(&a);
+            match acc {
+                Some((min, b)) if min <= k => Some((min, b)),
+                _ => Some((k, a)),
+            }
+        })
+        .map(|(_, a)| a)
+    }
+
+    fn maximum_option_by_key<K: Ord>(
+        self,
+        mut key: impl FnMut(&Self::Inner) -> K,
+    ) -> Option<Self::Inner>
+    where
+        Self: Sized,
+    {
+        self.fold_left(None, |acc: Option<(K, Self::Inner)>, a| {
+            let k = key(&a);
+            match acc {
+                Some((max, b)) if max > k => Some((max, b)),
+                _ => Some((k, a)),
+            }
+        })
+        .map(|(_, a)| a)
+    }
+
+    /// Folds left inside the monad `G`. `f` is no longer called once `G`
+    /// short-circuits, e.g. after a `None` or an `Err`. A branching monad like
+    /// `Vec` runs each step once per branch, so every element is cloned.
+    fn fold_m<G, B>(self, start: B, mut f: impl FnMut(B, Self::Inner) -> G) -> G
+    where
+        Self: Sized,
+        Self::Inner: Clone,
+        G: Monad<Inner = B, Outter<B> = G>,
+    {
+        self.fold_left(G::pure(start), |acc, a| {
+            acc.flat_map::<B, _>(|b| f(b, a.clone()))
+        })
+    }
+
+    /// Runs `f` on every element for its effect in `G`, discarding the
+    /// results.
+    fn traverse_<G>(self, mut f: impl FnMut(Self::Inner) -> G) -> G::Outter<()>
+    where
+        Self: Sized,
+        G: TraverseApplicative<()>,
+    {
+        self.fold_left(G::pure_acc(()), |acc, a| {
+            G::accumulate(acc, f(a), |(), _| ())
+        })
+    }
+
+    fn sequence_<G>(self) -> G::Outter<()>
+    where
+        Self: Foldable<Inner = G> + Sized,
+        G: TraverseApplicative<()>,
+    {
+        self.traverse_(|g| g)
+    }
+
+    fn partition_either<L, R>(
+        self,
+        mut f: impl FnMut(Self::Inner) -> Either<L, R>,
+    ) -> (Vec<L>, Vec<R>)
+    where
+        Self: Sized,
+    {
+        self.fold_left((Vec::new(), Vec::new()), |(mut ls, mut rs), a| {
+            match f(a) {
+                Left(l) => ls.push(l),
+                Right(r) => rs.push(r),
+            }
+            (ls, rs)
+        })
+    }
+
+    fn intercalate(self, separator: Self::Inner) -> Self::Inner
+    where
+        Self: Sized,
+        Self::Inner: Monoid + Clone,
+    {
+        Monoid::intercalate(self.fold_to_vec(), separator)
+    }
+
+    fn fold_map<M: Monoid>(self, mut f: impl FnMut(Self::Inner) -> M) -> M
+    where
+        Self: Sized,
+    {
+        self.fold_left(M::empty(), |acc, a| acc.combine(f(a)))
+    }
+
+    /// Like `fold_map`, but combining through `MonoidK`, e.g. `Option`
+    /// keeps the first `Some` instead of combining their contents.
+    fn fold_map_k<G>(self, mut f: impl FnMut(Self::Inner) -> G) -> G
+    where
+        Self: Sized,
+        G: MonoidK + SemigroupK<Outter<<G as SemigroupK>::Inner> = G>,
+    {
+        self.fold_left(<G as MonoidK>::empty(), |acc, a| acc.combine_k(f(a)))
+    }
+
+    /// Combines the elements left to right, or `None` if there are none.
+    fn combine_all_option(self) -> Option<Self::Inner>
+    where
+        Self: Sized,
+        Self::Inner: Semigroup,
+    {
+        self.fold_left(None, |acc, a| match acc {
+            None => Some(a),
+            Some(acc) => Some(acc.combine(a)),
+        })
+    }
+
+    /// Like `fold_map`, but with an explicit instance instead of `M: Monoid`.
+    fn fold_with<M>(self, instance: &MonoidInstance<M>, mut f: impl FnMut(Self::Inner) -> M) -> M
+    where
+        Self: Sized,
+    {
+        self.fold_left(instance.empty(), |acc, a| instance.combine(acc, f(a)))
+    }
+
+    fn combine_all_with(self, instance: &MonoidInstance<Self::Inner>) -> Self::Inner
+    where
+        Self: Sized,
+    {
+        self.fold_with(instance, |a| a)
+    }
 }
 
 pub trait MonoidFoldable<T: Monoid>: Foldable<Inner = T>
@@ -13,12 +282,68 @@ where
     Self: Sized,
 {
     fn folding(self) -> Self::Inner {
-        self.fold_left(Self::Inner::empty(), |acc, x| x.combine(acc))
+        self.fold_left(Self::Inner::empty(), |mut acc, x| {
+            acc.combine_assign(x);
+            acc
+        })
     }
 }
 
 impl<E: Foldable<Inner = T>, T: Monoid> MonoidFoldable<T> for E {}
 
+/// Combines the elements as a balanced tree instead of left to right. For
+/// `f32`/`f64` this is pairwise summation, whose rounding error grows with
+/// `O(log n)` rather than `O(n)`.
+pub trait PairwiseSum<T: Monoid>: Foldable<Inner = T>
+where
+    Self: Sized,
+{
+    fn pairwise_sum(self) -> Self::Inner {
+        let elements = self.fold_to_vec();
+        let len = elements.len();
+        pairwise(&mut elements.into_iter(), len)
+    }
+}
+
+impl<E: Foldable<Inner = T>, T: Monoid> PairwiseSum<T> for E {}
+
+/// Combines the next `len` elements, splitting them in halves by count so no
+/// level of the recursion allocates.
+fn pairwise<T: Monoid>(elements: &mut impl Iterator<Item = T>, len: usize) -> T {
+    if len <= 8 {
+        return elements.take(len).fold(T::empty(), T::combine);
+    }
+    let left = pairwise(elements, len / 2);
+    left.combine(pairwise(elements, len - len / 2))
+}
+
+/// One level of `fold_right_lazy`. The element is moved out of `elements`
+/// the first time the level is forced and the resulting `Eval` is kept, so
+/// forcing it again doesn't need the element a second time.
+fn lazy_fold_right<A: 'static, B: Clone + 'static>(
+    elements: std::vec::IntoIter<A>,
+    end: Eval<B>,
+    f: Rc<dyn Fn(A, Eval<B>) -> Eval<B>>,
+) -> Eval<B> {
+    let elements = RefCell::new(Some(elements));
+    let built: RefCell<Option<Eval<B>>> = RefCell::new(None);
+    Eval::defer(move || {
+        if let Some(eval) = built.borrow().clone() {
+            return eval;
+        }
+        let mut rest = elements
+            .borrow_mut()
+            .take()
+            .expect("fold_right_lazy panicked while building this level");
+        let eval = match rest.next() {
+            None => end.clone(),
+            Some(a) => f(a, lazy_fold_right(rest, end.clone(), f.clone())),
+        };
+        *built.borrow_mut() = Some(eval.clone());
+        eval
+    })
+}
+
 impl<T> Foldable for Vec<T> {
     type Inner = T;
 
@@ -29,9 +354,17 @@ impl<T> Foldable for Vec<T> {
     }
 
     fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
-        //TODO should be lazy?
+        // Eager; `fold_right_lazy` is the variant that can stop early.
         self.into_iter().rev().fold(start, f)
     }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        self.into_iter().try_fold(start, f)
+    }
 }
 
 impl<T> Foldable for Option<T> {
@@ -46,11 +379,223 @@ impl<T> Foldable for Option<T> {
     fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
         self.into_iter().rev().fold(start, f)
     }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        self.into_iter().try_fold(start, f)
+    }
+}
+
+impl<T, E> Foldable for Result<T, E> {
+    type Inner = T;
+
+    type Outter<B> = Result<B, E>;
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_iter().fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_iter().rev().fold(start, f)
+    }
+
+    fn try_fold_left<B, E2>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E2>,
+    ) -> Result<B, E2> {
+        self.into_iter().try_fold(start, f)
+    }
+}
+
+/// Folds over the values, in the map's iteration order.
+impl<K, V, S> Foldable for HashMap<K, V, S> {
+    type Inner = V;
+
+    type Outter<B> = HashMap<K, B, S>;
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_values().fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        let values: Vec<_> = self.into_values().collect();
+        values.into_iter().rev().fold(start, f)
+    }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        self.into_values().try_fold(start, f)
+    }
+}
+
+impl<T, S> Foldable for HashSet<T, S> {
+    type Inner = T;
+
+    type Outter<B> = HashSet<B, S>;
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_iter().fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        let elements: Vec<_> = self.into_iter().collect();
+        elements.into_iter().rev().fold(start, f)
+    }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        self.into_iter().try_fold(start, f)
+    }
+}
+
+/// Folds over the values, in key order.
+impl<K, V> Foldable for BTreeMap<K, V> {
+    type Inner = V;
+
+    type Outter<B> = BTreeMap<K, B>;
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_values().fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_values().rev().fold(start, f)
+    }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        self.into_values().try_fold(start, f)
+    }
+}
+
+impl<T> Foldable for BTreeSet<T> {
+    type Inner = T;
+
+    type Outter<B> = BTreeSet<B>;
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_iter().fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_iter().rev().fold(start, f)
+    }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        self.into_iter().try_fold(start, f)
+    }
+}
+
+impl<T> Foldable for VecDeque<T> {
+    type Inner = T;
+
+    type Outter<B> = VecDeque<B>;
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_iter().fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_iter().rev().fold(start, f)
+    }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        self.into_iter().try_fold(start, f)
+    }
+}
+
+impl<T> Foldable for LinkedList<T> {
+    type Inner = T;
+
+    type Outter<B> = LinkedList<B>;
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_iter().fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_iter().rev().fold(start, f)
+    }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        self.into_iter().try_fold(start, f)
+    }
+}
+
+impl<T, const N: usize> Foldable for [T; N] {
+    type Inner = T;
+
+    type Outter<B> = [B; N];
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        IntoIterator::into_iter(self).fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        IntoIterator::into_iter(self).rev().fold(start, f)
+    }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        IntoIterator::into_iter(self).try_fold(start, f)
+    }
+}
+
+impl<T> Foldable for Box<T> {
+    type Inner = T;
+
+    type Outter<B> = Box<B>;
+
+    fn fold_left<B>(self, start: B, mut f: impl FnMut(B, Self::Inner) -> B) -> B {
+        f(start, *self)
+    }
+
+    fn fold_right<B>(self, start: B, mut f: impl FnMut(B, Self::Inner) -> B) -> B {
+        f(start, *self)
+    }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        mut f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        f(start, *self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data::either::{Left, Right};
+    use crate::kernel::wrappers::Max;
 
     #[quickcheck]
     fn folding_consistent_with_sum(x: Vec<i32>) {
@@ -61,4 +606,359 @@ mod tests {
             cloned.iter().fold(0i32, |acc, x| acc.wrapping_add(*x))
         )
     }
+
+    #[quickcheck]
+    fn pairwise_sum_keeps_the_element_order(x: Vec<String>) -> bool {
+        x.concat() == x.pairwise_sum()
+    }
+
+    #[test]
+    fn pairwise_sum_drifts_less_than_folding() {
+        let values = vec![0.1f32; 100_000];
+        let folded = values.clone().folding();
+        let pairwise = values.pairwise_sum();
+        assert!((pairwise - 10_000.0).abs() < 0.01);
+        assert!((folded - 10_000.0).abs() > 1.0);
+    }
+
+    #[quickcheck]
+    fn combine_all_with_the_max_instance(x: Vec<u64>) -> bool {
+        let max = MonoidInstance::via(Max, |m: Max<u64>| m.0);
+        x.clone().combine_all_with(&max) == x.into_iter().max().unwrap_or(0)
+    }
+
+    #[quickcheck]
+    fn fold_with_agrees_with_fold_map(x: Vec<u32>) -> bool {
+        let instance = MonoidInstance::of();
+        x.clone().fold_with(&instance, |a| a.to_string()) == x.fold_map(|a| a.to_string())
+    }
+
+    #[quickcheck]
+    fn fold_map_agrees_with_concat(x: Vec<String>) -> bool {
+        x.iter().map(|s| s.to_uppercase()).collect::<String>() == x.fold_map(|s| s.to_uppercase())
+    }
+
+    #[quickcheck]
+    fn fold_map_k_agrees_with_concat(x: Vec<Vec<u8>>) -> bool {
+        x.concat() == x.fold_map_k(|v| v)
+    }
+
+    #[test]
+    fn fold_map_k_keeps_the_first_some() {
+        let found = vec![1, 4, 6, 8].fold_map_k(|x| if x % 2 == 0 { Some(x) } else { None });
+        assert_eq!(found, Some(4));
+    }
+
+    #[quickcheck]
+    fn combine_all_option_agrees_with_concat(x: Vec<Vec<u8>>, s: Vec<String>) -> bool {
+        (!x.is_empty()).then(|| x.concat()) == x.combine_all_option()
+            && (!s.is_empty()).then(|| s.concat()) == s.combine_all_option()
+    }
+
+    #[quickcheck]
+    fn folding_keeps_the_element_order(x: Vec<String>) -> bool {
+        x.concat() == x.folding()
+    }
+
+    #[test]
+    fn folding_strings_is_left_to_right() {
+        assert_eq!(vec!["a".to_string(), "b".to_string()].folding(), "ab");
+    }
+
+    #[quickcheck]
+    fn find_agrees_with_iterator(x: Vec<u8>, n: u8) -> bool {
+        x.iter().copied().find(|a| *a > n) == x.find(|a| *a > n)
+    }
+
+    #[quickcheck]
+    fn collect_first_agrees_with_iterator(x: Vec<u8>) -> bool {
+        let f = |a: u8| a.checked_mul(3).filter(|b| *b > 100);
+        x.iter().copied().find_map(f) == x.collect_first(f)
+    }
+
+    #[quickcheck]
+    fn exists_and_forall_agree_with_iterator(x: Vec<u8>, n: u8) -> bool {
+        x.iter().any(|a| *a > n) == x.clone().exists(|a| *a > n)
+            && x.iter().all(|a| *a > n) == x.forall(|a| *a > n)
+    }
+
+    #[quickcheck]
+    fn size_and_fold_is_empty_agree_with_iterator(x: Vec<u8>) -> bool {
+        x.len() == x.clone().size() && x.is_empty() == x.fold_is_empty()
+    }
+
+    #[quickcheck]
+    fn fold_to_vec_and_fold_get_agree_with_iterator(x: Option<u8>, y: Vec<u8>, i: usize) -> bool {
+        x.iter().copied().collect::<Vec<_>>() == x.fold_to_vec()
+            && y.get(i % 10).copied() == y.fold_get(i % 10)
+    }
+
+    #[quickcheck]
+    fn reduce_left_option_agrees_with_iterator(x: Vec<String>) -> bool {
+        x.iter().cloned().reduce(|a, b| a + &b) == x.reduce_left_option(|a, b| a + &b)
+    }
+
+    #[quickcheck]
+    fn minimum_and_maximum_agree_with_iterator(x: Vec<(u8, u8)>) -> bool {
+        x.iter().copied().min() == x.clone().minimum_option()
+            && x.iter().copied().max() == x.clone().maximum_option()
+            && x.iter().copied().min_by_key(|p| p.0) == x.clone().minimum_option_by_key(|p| p.0)
+            && x.iter().copied().max_by_key(|p| p.0) == x.maximum_option_by_key(|p| p.0)
+    }
+
+    #[quickcheck]
+    fn fold_m_agrees_with_try_fold(x: Vec<u8>) -> bool {
+        let step = |acc: u8, a: u8| acc.checked_add(a);
+        x.iter().copied().try_fold(0u8, step) == x.fold_m(0u8, step)
+    }
+
+    #[quickcheck]
+    fn fold_m_branches_in_vec(x: Vec<u8>) -> bool {
+        let x: Vec<u16> = x.into_iter().take(8).map(u16::from).collect();
+        let expected = x.iter().fold(vec![0u16], |sums, a| {
+            sums.into_iter().flat_map(|s| vec![s + a, s]).collect()
+        });
+        expected == x.fold_m(0u16, |acc, a| vec![acc + a, acc])
+    }
+
+    #[quickcheck]
+    fn traverse_agrees_with_iterator(x: Vec<u8>) -> bool {
+        let check = |a: u8| if a < 200 { Ok(a) } else { Err(a) };
+        x.iter().copied().try_for_each(|a| check(a).map(|_| ())) == x.traverse_(check)
+    }
+
+    #[quickcheck]
+    fn sequence_agrees_with_iterator(x: Vec<Option<u8>>) -> bool {
+        x.iter().all(Option::is_some).then_some(()) == x.sequence_()
+    }
+
+    #[quickcheck]
+    fn partition_either_agrees_with_iterator(x: Vec<u8>) -> bool {
+        let (small, large): (Vec<u8>, Vec<u8>) = x.iter().partition(|a| **a < 128);
+        let to_either = |a: u8| if a < 128 { Left(a) } else { Right(a) };
+        (small, large) == x.partition_either(to_either)
+    }
+
+    #[quickcheck]
+    fn intercalate_agrees_with_join(x: Vec<String>, separator: String) -> bool {
+        x.join(&separator) == x.intercalate(separator)
+    }
+
+    #[test]
+    fn searches_short_circuit() {
+        let mut calls = 0;
+        let found = vec![1, 2, 3, 4, 5].find(|a| {
+            calls += 1;
+            *a == 2
+        });
+        assert_eq!((found, calls), (Some(2), 2));
+
+        let mut calls = 0;
+        let all = vec![1, 2, 3, 4, 5].forall(|a| {
+            calls += 1;
+            *a < 3
+        });
+        assert_eq!((all, calls), (false, 3));
+
+        let mut calls = 0;
+        let folded = vec![1u8, 2, 3, 4].fold_m(0u8, |acc, a| {
+            calls += 1;
+            if a == 2 {
+                None
+            } else {
+                Some(acc + a)
+            }
+        });
+        assert_eq!((folded, calls), (None, 2));
+    }
+
+    #[quickcheck]
+    fn fold_right_lazy_agrees_with_fold_right(x: Vec<String>) -> bool {
+        let eager = x.clone().fold_right(String::new(), |acc, a| a + &acc);
+        let lazy = x.fold_right_lazy(Eval::now(String::new()), |a, rest| {
+            rest.map(move |acc| a.clone() + &acc)
+        });
+        eager == lazy.value()
+    }
+
+    #[quickcheck]
+    fn exists_with_fold_right_lazy_agrees_with_iterator(x: Vec<u8>, n: u8) -> bool {
+        let lazy =
+            x.clone().fold_right_lazy(
+                Eval::now(false),
+                move |a, rest| {
+                    if a > n {
+                        Eval::now(true)
+                    } else {
+                        rest
+                    }
+                },
+            );
+        x.iter().any(|a| *a > n) == lazy.value()
+    }
+
+    #[test]
+    fn fold_right_lazy_moves_the_elements() {
+        struct Word(&'static str);
+        let words = vec![Word("a"), Word("b"), Word("c")];
+        let joined = words.fold_right_lazy(Eval::now(String::new()), |w, rest| {
+            rest.map(move |acc| w.0.to_string() + &acc)
+        });
+        assert_eq!(joined.clone().value(), "abc");
+        assert_eq!(joined.value(), "abc");
+    }
+
+    #[test]
+    fn fold_right_lazy_stops_early() {
+        let visited = Rc::new(std::cell::Cell::new(0));
+        let counter = visited.clone();
+        let found =
+            (1..=1000)
+                .collect::<Vec<u32>>()
+                .fold_right_lazy(Eval::now(None), move |a, rest| {
+                    counter.set(counter.get() + 1);
+                    if a == 3 {
+                        Eval::now(Some(a))
+                    } else {
+                        rest
+                    }
+                });
+        assert_eq!(found.value(), Some(3));
+        assert_eq!(visited.get(), 3);
+    }
+
+    #[test]
+    fn fold_right_lazy_is_stack_safe() {
+        let sum =
+            vec![1u64; 1_000_000].fold_right_lazy(Eval::now(0), |a, rest| rest.map(move |b| a + b));
+        assert_eq!(sum.value(), 1_000_000);
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+
+    type VecUsize = Vec<usize>;
+    type OptionUsize = Option<usize>;
+    type ResultUsizeUsize = Result<usize, usize>;
+    type HashMapUsizeUsize = HashMap<usize, usize>;
+    type HashSetUsize = HashSet<usize>;
+    type BTreeMapUsizeUsize = BTreeMap<usize, usize>;
+    type BTreeSetUsize = BTreeSet<usize>;
+    type VecDequeUsize = VecDeque<usize>;
+    type LinkedListUsize = LinkedList<usize>;
+    type ArrayUsize5 = [usize; 5];
+    type BoxUsize = Box<usize>;
+
+    macro_rules! fold_right_reverses_fold_left {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                fold_right_reverses_fold_left!([<fold_right_reverses_fold_left_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(x: $t) -> bool {
+                use crate::core::prelude::*;
+                let push = |mut acc: Vec<usize>, a| {
+                    acc.push(a);
+                    acc
+                };
+                let mut left = x.clone().fold_left(Vec::new(), push);
+                left.reverse();
+                left == x.fold_right(Vec::new(), push)
+            }
+        };
+    }
+
+    macro_rules! fold_map_consistent_with_fold_left {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                fold_map_consistent_with_fold_left!([<fold_map_consistent_with_fold_left_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(x: $t) -> bool {
+                use crate::core::prelude::*;
+                let folded = x.clone().fold_left(String::new(), |acc, a| acc + &a.to_string());
+                folded == x.fold_map(|a| a.to_string())
+            }
+        };
+    }
+
+    macro_rules! try_fold_left_consistent_with_fold_left {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                try_fold_left_consistent_with_fold_left!([<try_fold_left_consistent_with_fold_left_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(x: $t) -> bool {
+                use crate::core::prelude::*;
+                let step = |acc: usize, a: usize| acc.wrapping_mul(31).wrapping_add(a);
+                let folded = x.clone().fold_left(0, step);
+                x.try_fold_left(0, |acc, a| Ok::<_, ()>(step(acc, a))) == Ok(folded)
+            }
+        };
+    }
+
+    mod fold_right_reverses_fold_left {
+        use super::*;
+
+        fold_right_reverses_fold_left!(
+            VecUsize,
+            OptionUsize,
+            ResultUsizeUsize,
+            HashMapUsizeUsize,
+            HashSetUsize,
+            BTreeMapUsizeUsize,
+            BTreeSetUsize,
+            VecDequeUsize,
+            LinkedListUsize,
+            ArrayUsize5,
+            BoxUsize
+        );
+    }
+
+    mod fold_map_consistent_with_fold_left {
+        use super::*;
+
+        fold_map_consistent_with_fold_left!(
+            VecUsize,
+            OptionUsize,
+            ResultUsizeUsize,
+            HashMapUsizeUsize,
+            HashSetUsize,
+            BTreeMapUsizeUsize,
+            BTreeSetUsize,
+            VecDequeUsize,
+            LinkedListUsize,
+            ArrayUsize5,
+            BoxUsize
+        );
+    }
+
+    mod try_fold_left_consistent_with_fold_left {
+        use super::*;
+
+        try_fold_left_consistent_with_fold_left!(
+            VecUsize,
+            OptionUsize,
+            ResultUsizeUsize,
+            HashMapUsizeUsize,
+            HashSetUsize,
+            BTreeMapUsizeUsize,
+            BTreeSetUsize,
+            VecDequeUsize,
+            LinkedListUsize,
+            ArrayUsize5,
+            BoxUsize
+        );
+    }
 }
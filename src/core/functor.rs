@@ -1,3 +1,6 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
 pub trait Functor {
     type Inner;
     type Outter<B>: Functor;
@@ -11,11 +14,11 @@ impl<A> Functor for Option<A> {
     type Inner = A;
     type Outter<B> = Option<B>;
 
-    fn fmap<F, B>(self, mut f: F) -> Self::Outter<B>
+    fn fmap<F, B>(self, f: F) -> Self::Outter<B>
     where
         F: FnMut(Self::Inner) -> B,
     {
-        self.map(|v| f(v))
+        self.map(f)
     }
 }
 
@@ -46,19 +49,34 @@ impl<A> Functor for Vec<A> {
     }
 }
 
-// TODO handle these bounds
-// impl<A: Eq + Hash> Functor for HashSet<A> {
-//     type Inner = A;
-//     type Outter<B> = HashSet<B>;
+/// Maps the values, keeping the keys.
+impl<K: Eq + Hash, V, S: BuildHasher + Default> Functor for HashMap<K, V, S> {
+    type Inner = V;
+    type Outter<B> = HashMap<K, B, S>;
+
+    fn fmap<F, B>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+/// Maps the values, keeping the keys.
+impl<K: Ord, V> Functor for BTreeMap<K, V> {
+    type Inner = V;
+    type Outter<B> = BTreeMap<K, B>;
+
+    fn fmap<F, B>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
 
-//     fn fmap<F, B>(self, f: F) -> Self::Outter<B>
-//     where
-//         F: FnMut(Self::Inner) -> B,
-//     {
-//         self.into_iter().map(f).collect()
-//     }
-// }
-//
+// Sets can't be functors here: `Outter<B>` has to be a `Functor` for every
+// `B`, but building a `HashSet<B>` or `BTreeSet<B>` needs bounds on `B`.
 
 pub fn lift<A: Functor, B>(
     fun: impl FnMut(<A as Functor>::Inner) -> B + Copy,
@@ -96,9 +114,13 @@ mod tests {
 
 #[cfg(test)]
 mod laws {
+    use std::collections::{BTreeMap, HashMap};
+
     type OptionUsize = Option<usize>;
     type ResultUsizeUsize = Result<usize, usize>;
     type VecUsize = Vec<usize>;
+    type HashMapUsizeUsize = HashMap<usize, usize>;
+    type BTreeMapUsizeUsize = BTreeMap<usize, usize>;
 
     macro_rules! preserve_identity {
         ($($t:ty),*) => {$(
@@ -122,10 +144,36 @@ mod laws {
         };
     }
 
+    macro_rules! composition_identity {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                composition_identity!([<composition_identity_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t) -> bool {
+                use crate::core::prelude::*;
+                let f1 = |a: usize| (a / 5) * 2;
+                let f2 = |a: usize| (a / 5) * 3;
+                let n1_copy = n1.clone();
+
+                n1.fmap(f1).fmap(f2) == n1_copy.fmap(|a| f2(f1(a)))
+            }
+        };
+    }
+
     mod preserve_identity {
         use super::*;
 
-        preserve_identity!(OptionUsize, ResultUsizeUsize, VecUsize);
+        preserve_identity!(
+            OptionUsize,
+            ResultUsizeUsize,
+            VecUsize,
+            HashMapUsizeUsize,
+            BTreeMapUsizeUsize
+        );
     }
 
     mod composition_identity {
@@ -160,5 +208,7 @@ mod laws {
 
             assert_eq!(res.fmap(f1).fmap(f2), res_copy.fmap(|a| f2(f1(a))))
         }
+
+        composition_identity!(HashMapUsizeUsize, BTreeMapUsizeUsize);
     }
 }
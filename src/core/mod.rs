@@ -7,7 +7,9 @@ pub mod foldable;
 pub mod functor;
 pub mod monad;
 pub mod monoid_k;
+pub mod reducible;
 pub mod semigroup_k;
+pub mod traverse;
 
 pub mod std_kinds {
     use std::marker::PhantomData;
@@ -30,6 +32,8 @@ pub mod prelude {
     pub use super::functor::*;
     pub use super::monad::*;
     pub use super::monoid_k::*;
+    pub use super::reducible::*;
     pub use super::semigroup_k::*;
     pub use super::std_kinds::*;
+    pub use super::traverse::*;
 }
@@ -0,0 +1,71 @@
+use super::foldable::Foldable;
+use crate::kernel::prelude::Semigroup;
+
+/// A [`Foldable`] that always holds at least one element, so it can be
+/// reduced without a starting value.
+pub trait Reducible: Foldable {
+    fn reduce_left_to<B>(
+        self,
+        f: impl FnOnce(Self::Inner) -> B,
+        g: impl FnMut(B, Self::Inner) -> B,
+    ) -> B;
+
+    fn reduce_right_to<B>(
+        self,
+        f: impl FnOnce(Self::Inner) -> B,
+        g: impl FnMut(B, Self::Inner) -> B,
+    ) -> B;
+
+    fn reduce_left(self, f: impl FnMut(Self::Inner, Self::Inner) -> Self::Inner) -> Self::Inner
+    where
+        Self: Sized,
+    {
+        self.reduce_left_to(|a| a, f)
+    }
+
+    fn reduce_right(self, f: impl FnMut(Self::Inner, Self::Inner) -> Self::Inner) -> Self::Inner
+    where
+        Self: Sized,
+    {
+        self.reduce_right_to(|a| a, f)
+    }
+
+    fn reduce(self) -> Self::Inner
+    where
+        Self: Sized,
+        Self::Inner: Semigroup,
+    {
+        self.reduce_left(Semigroup::combine)
+    }
+
+    fn reduce_map<B: Semigroup>(self, mut f: impl FnMut(Self::Inner) -> B) -> B
+    where
+        Self: Sized,
+    {
+        // `f` is threaded through the accumulator since both steps need it
+        self.reduce_left_to(
+            move |a| (f(a), f),
+            |(b, mut f), a| {
+                let b = b.combine(f(a));
+                (b, f)
+            },
+        )
+        .0
+    }
+
+    fn minimum(self) -> Self::Inner
+    where
+        Self: Sized,
+        Self::Inner: Ord,
+    {
+        self.reduce_left(std::cmp::min)
+    }
+
+    fn maximum(self) -> Self::Inner
+    where
+        Self: Sized,
+        Self::Inner: Ord,
+    {
+        self.reduce_left(std::cmp::max)
+    }
+}
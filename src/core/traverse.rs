@@ -0,0 +1,402 @@
+use super::applicative::Applicative;
+use super::foldable::Foldable;
+use super::functor::Functor;
+
+/// An [`Applicative`] a [`Traverse`] can rebuild its structure into.
+///
+/// `T` is the structure being rebuilt, e.g. the `Vec<B>` of a traversed `Vec`.
+///
+/// There is no blanket impl over `Applicative`: accumulating needs
+/// `Self::Outter<T>` to be an applicative for every `T`, which the GAT
+/// encoding can't express. Every `Applicative` in this crate implements it;
+/// one defined elsewhere needs its own impl to be traversed into.
+pub trait TraverseApplicative<T>: Applicative {
+    fn pure_acc(value: T) -> Self::Outter<T>;
+
+    fn accumulate<F>(acc: Self::Outter<T>, elem: Self, f: F) -> Self::Outter<T>
+    where
+        F: FnMut(T, Self::Inner) -> T;
+}
+
+impl<A, T> TraverseApplicative<T> for Option<A> {
+    fn pure_acc(value: T) -> Self::Outter<T> {
+        Some(value)
+    }
+
+    fn accumulate<F>(acc: Self::Outter<T>, elem: Self, mut f: F) -> Self::Outter<T>
+    where
+        F: FnMut(T, Self::Inner) -> T,
+    {
+        Some(f(acc?, elem?))
+    }
+}
+
+impl<A, E, T> TraverseApplicative<T> for Result<A, E> {
+    fn pure_acc(value: T) -> Self::Outter<T> {
+        Ok(value)
+    }
+
+    fn accumulate<F>(acc: Self::Outter<T>, elem: Self, mut f: F) -> Self::Outter<T>
+    where
+        F: FnMut(T, Self::Inner) -> T,
+    {
+        Ok(f(acc?, elem?))
+    }
+}
+
+impl<A: Clone, T: Clone> TraverseApplicative<T> for Vec<A> {
+    fn pure_acc(value: T) -> Self::Outter<T> {
+        vec![value]
+    }
+
+    fn accumulate<F>(acc: Self::Outter<T>, elem: Self, mut f: F) -> Self::Outter<T>
+    where
+        F: FnMut(T, Self::Inner) -> T,
+    {
+        let mut combined = Vec::with_capacity(acc.len() * elem.len());
+        for value in acc {
+            for inner in elem.iter() {
+                combined.push(f(value.clone(), inner.clone()));
+            }
+        }
+        combined
+    }
+}
+
+/// Traverses into any `G` with a [`TraverseApplicative`] instance, rather
+/// than any [`Applicative`]; see that trait for why.
+pub trait Traverse: Functor + Foldable {
+    fn traverse<G, B, F>(self, f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = B>,
+        F: FnMut(<Self as Functor>::Inner) -> G;
+
+    fn flat_traverse<G, B, F>(self, f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = <Self as Functor>::Outter<B>>,
+        F: FnMut(<Self as Functor>::Inner) -> G;
+
+    fn sequence<G, B>(self) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        Self: Functor<Inner = G> + Sized,
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = B>,
+    {
+        self.traverse(|g| g)
+    }
+
+    fn traverse_with_index<G, B, F>(self, mut f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        Self: Sized,
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = B>,
+        F: FnMut(<Self as Functor>::Inner, usize) -> G,
+    {
+        let mut index = 0;
+        self.traverse(|a| {
+            let g = f(a, index);
+            index += 1;
+            g
+        })
+    }
+}
+
+impl<A> Traverse for Vec<A> {
+    fn traverse<G, B, F>(self, f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = B>,
+        F: FnMut(<Self as Functor>::Inner) -> G,
+    {
+        let start = G::pure_acc(Vec::with_capacity(self.len()));
+        self.into_iter().map(f).fold(start, |acc, g| {
+            G::accumulate(acc, g, |mut values, b| {
+                values.push(b);
+                values
+            })
+        })
+    }
+
+    fn flat_traverse<G, B, F>(self, f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = <Self as Functor>::Outter<B>>,
+        F: FnMut(<Self as Functor>::Inner) -> G,
+    {
+        let start = G::pure_acc(Vec::new());
+        self.into_iter().map(f).fold(start, |acc, g| {
+            G::accumulate(acc, g, |mut values, mut bs| {
+                values.append(&mut bs);
+                values
+            })
+        })
+    }
+}
+
+impl<A> Traverse for Option<A> {
+    fn traverse<G, B, F>(self, mut f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = B>,
+        F: FnMut(<Self as Functor>::Inner) -> G,
+    {
+        match self {
+            Some(a) => f(a).fmap(Some),
+            None => G::pure_acc(None),
+        }
+    }
+
+    fn flat_traverse<G, B, F>(self, mut f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = <Self as Functor>::Outter<B>>,
+        F: FnMut(<Self as Functor>::Inner) -> G,
+    {
+        match self {
+            Some(a) => f(a).fmap(|b| b),
+            None => G::pure_acc(None),
+        }
+    }
+}
+
+impl<A, E> Traverse for Result<A, E> {
+    fn traverse<G, B, F>(self, mut f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = B>,
+        F: FnMut(<Self as Functor>::Inner) -> G,
+    {
+        match self {
+            Ok(a) => f(a).fmap(Ok),
+            Err(e) => G::pure_acc(Err(e)),
+        }
+    }
+
+    fn flat_traverse<G, B, F>(self, mut f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = <Self as Functor>::Outter<B>>,
+        F: FnMut(<Self as Functor>::Inner) -> G,
+    {
+        match self {
+            Ok(a) => f(a).fmap(|b| b),
+            Err(e) => G::pure_acc(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Traverse;
+
+    #[test]
+    fn vec_of_results_is_sequenced_into_result_of_vec() {
+        let values: Vec<Result<u64, String>> = vec![Ok(1), Ok(2), Ok(3)];
+        assert_eq!(values.sequence(), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn vec_of_results_stops_at_first_err() {
+        let values: Vec<Result<u64, &str>> = vec![Ok(1), Err("first"), Err("second")];
+        assert_eq!(values.sequence(), Err("first"));
+    }
+
+    #[test]
+    fn vec_traverse_option() {
+        let decrement = |x: u64| x.checked_sub(1);
+        assert_eq!(vec![2, 4, 6].traverse(decrement), Some(vec![1, 3, 5]));
+        assert_eq!(vec![2, 0, 6].traverse(decrement), None);
+        assert_eq!(Vec::new().traverse(decrement), Some(vec![]));
+    }
+
+    #[test]
+    fn vec_traverse_vec_is_cartesian_product() {
+        let values = vec![vec![1, 2], vec![3, 4]];
+        assert_eq!(
+            values.sequence(),
+            vec![vec![1, 3], vec![1, 4], vec![2, 3], vec![2, 4]]
+        );
+    }
+
+    #[test]
+    fn option_traverse() {
+        let parse = |s: &str| s.parse::<u64>();
+        assert_eq!(Some("42").traverse(parse), Ok(Some(42)));
+        assert_eq!(None.traverse(parse), Ok(None));
+        assert!(Some("nope").traverse(parse).is_err());
+    }
+
+    #[test]
+    fn result_traverse() {
+        let decrement = |x: u64| x.checked_sub(1);
+        assert_eq!(Result::<u64, ()>::Ok(4).traverse(decrement), Some(Ok(3)));
+        assert_eq!(Result::<u64, ()>::Ok(0).traverse(decrement), None);
+        assert_eq!(
+            Result::<u64, ()>::Err(()).traverse(decrement),
+            Some(Err(()))
+        );
+    }
+
+    #[test]
+    fn flat_traverse_flattens_inner_structure() {
+        let repeat = |x: usize| Some(vec![x; x]);
+        assert_eq!(
+            vec![1, 2, 3].flat_traverse(repeat),
+            Some(vec![1, 2, 2, 3, 3, 3])
+        );
+        assert_eq!(
+            Some(2).flat_traverse(|x: u64| Ok::<_, ()>(Some(x + 1))),
+            Ok(Some(3))
+        );
+        assert_eq!(
+            Result::<u64, ()>::Ok(2).flat_traverse(|_| Some(Err::<u64, ()>(()))),
+            Some(Err(()))
+        );
+    }
+
+    #[test]
+    fn traverse_with_index_passes_positions() {
+        let values = vec!["a", "b", "c"];
+        assert_eq!(
+            values.traverse_with_index(|s, i| Some(format!("{}{}", s, i))),
+            Some(vec!["a0".to_string(), "b1".to_string(), "c2".to_string()])
+        );
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::core::prelude::*;
+
+    type OptionUsize = Option<usize>;
+    type ResultUsizeUsize = Result<usize, usize>;
+    type VecUsize = Vec<usize>;
+
+    fn decrement(a: usize) -> Option<usize> {
+        a.checked_sub(1)
+    }
+
+    fn below_limit(a: usize) -> Result<usize, String> {
+        if a < usize::MAX / 4 {
+            Ok(a * 3)
+        } else {
+            Err(format!("{} is too large", a))
+        }
+    }
+
+    // Option<Result<_, String>> as a single applicative, used to state the
+    // composition law without relying on a general Nested type.
+    #[derive(Debug, PartialEq)]
+    struct Nested<A>(Option<Result<A, String>>);
+
+    impl<A> Functor for Nested<A> {
+        type Inner = A;
+        type Outter<B> = Nested<B>;
+
+        fn fmap<F, B>(self, f: F) -> Self::Outter<B>
+        where
+            F: FnMut(Self::Inner) -> B,
+        {
+            Nested(self.0.map(|r| r.map(f)))
+        }
+    }
+
+    impl<A> Apply for Nested<A> {
+        fn apply<B, F>(self, f: Self::Outter<F>) -> Self::Outter<B>
+        where
+            F: FnMut(Self::Inner) -> B,
+        {
+            Nested(self.0.zip(f.0).map(|(a, f)| a.apply(f)))
+        }
+    }
+
+    impl<A> Applicative for Nested<A> {
+        fn pure(value: Self::Inner) -> Self::Outter<Self::Inner> {
+            Nested(Some(Ok(value)))
+        }
+    }
+
+    impl<A, T> TraverseApplicative<T> for Nested<A> {
+        fn pure_acc(value: T) -> Self::Outter<T> {
+            Nested(Some(Ok(value)))
+        }
+
+        fn accumulate<F>(acc: Self::Outter<T>, elem: Self, mut f: F) -> Self::Outter<T>
+        where
+            F: FnMut(T, Self::Inner) -> T,
+        {
+            Nested(
+                acc.0
+                    .zip(elem.0)
+                    .map(|(acc, elem)| Result::accumulate(acc, elem, &mut f)),
+            )
+        }
+    }
+
+    macro_rules! traverse_identity {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                traverse_identity!([<traverse_identity_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t) -> bool {
+                let n1_copy = n1.clone();
+
+                n1.traverse(Some) == Some(n1_copy)
+            }
+        };
+    }
+
+    macro_rules! traverse_composition {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                traverse_composition!([<traverse_composition_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t) -> bool {
+                let n1_copy = n1.clone();
+
+                let left = n1.traverse(decrement).map(|fb| fb.traverse(below_limit));
+                let right = n1_copy.traverse(|a| Nested(decrement(a).map(below_limit)));
+
+                Nested(left) == right
+            }
+        };
+    }
+
+    macro_rules! traverse_naturality {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                traverse_naturality!([<traverse_naturality_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t) -> bool {
+                fn to_vec<A>(option: Option<A>) -> Vec<A> {
+                    option.into_iter().collect()
+                }
+                let n1_copy = n1.clone();
+
+                to_vec(n1.traverse(decrement)) == n1_copy.traverse(|a| to_vec(decrement(a)))
+            }
+        };
+    }
+
+    mod identity {
+        use super::*;
+
+        traverse_identity!(OptionUsize, ResultUsizeUsize, VecUsize);
+    }
+
+    mod composition {
+        use super::*;
+
+        traverse_composition!(OptionUsize, ResultUsizeUsize, VecUsize);
+    }
+
+    mod naturality {
+        use super::*;
+
+        traverse_naturality!(OptionUsize, ResultUsizeUsize, VecUsize);
+    }
+}
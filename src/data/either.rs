@@ -0,0 +1,343 @@
+use crate::core::prelude::*;
+use crate::kernel::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+pub use Either::{Left, Right};
+
+impl<L, R> Either<L, R> {
+    pub fn is_left(&self) -> bool {
+        matches!(self, Left(_))
+    }
+
+    pub fn is_right(&self) -> bool {
+        matches!(self, Right(_))
+    }
+
+    pub fn left(self) -> Option<L> {
+        match self {
+            Left(l) => Some(l),
+            Right(_) => None,
+        }
+    }
+
+    pub fn right(self) -> Option<R> {
+        match self {
+            Left(_) => None,
+            Right(r) => Some(r),
+        }
+    }
+
+    pub fn swap(self) -> Either<R, L> {
+        match self {
+            Left(l) => Right(l),
+            Right(r) => Left(r),
+        }
+    }
+
+    pub fn left_map<F, C>(self, f: F) -> Either<C, R>
+    where
+        F: FnOnce(L) -> C,
+    {
+        match self {
+            Left(l) => Left(f(l)),
+            Right(r) => Right(r),
+        }
+    }
+
+    pub fn fold<C, FL, FR>(self, fl: FL, fr: FR) -> C
+    where
+        FL: FnOnce(L) -> C,
+        FR: FnOnce(R) -> C,
+    {
+        match self {
+            Left(l) => fl(l),
+            Right(r) => fr(r),
+        }
+    }
+}
+
+impl<A> Either<A, A> {
+    pub fn merge(self) -> A {
+        match self {
+            Left(a) | Right(a) => a,
+        }
+    }
+}
+
+impl<L, R> From<Result<R, L>> for Either<L, R> {
+    fn from(result: Result<R, L>) -> Self {
+        match result {
+            Ok(r) => Right(r),
+            Err(l) => Left(l),
+        }
+    }
+}
+
+impl<L, R> From<Either<L, R>> for Result<R, L> {
+    fn from(either: Either<L, R>) -> Self {
+        match either {
+            Left(l) => Err(l),
+            Right(r) => Ok(r),
+        }
+    }
+}
+
+impl<L, R> Functor for Either<L, R> {
+    type Inner = R;
+    type Outter<B> = Either<L, B>;
+
+    fn fmap<F, B>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => Right(f(r)),
+        }
+    }
+}
+
+impl<L, R> Apply for Either<L, R> {
+    fn apply<B, F>(self, f: Self::Outter<F>) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        match f {
+            Left(l) => Left(l),
+            Right(f) => self.fmap(f),
+        }
+    }
+}
+
+impl<L, R> Applicative for Either<L, R> {
+    fn pure(value: Self::Inner) -> Self::Outter<Self::Inner> {
+        Right(value)
+    }
+}
+
+impl<L, R> FlatMap for Either<L, R> {
+    fn flat_map<B, F>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> Self::Outter<B>,
+    {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => f(r),
+        }
+    }
+}
+
+impl<L, R> Monad for Either<L, R> {}
+
+impl<L, R, T> TraverseApplicative<T> for Either<L, R> {
+    fn pure_acc(value: T) -> Self::Outter<T> {
+        Right(value)
+    }
+
+    fn accumulate<F>(acc: Self::Outter<T>, elem: Self, mut f: F) -> Self::Outter<T>
+    where
+        F: FnMut(T, Self::Inner) -> T,
+    {
+        match (acc, elem) {
+            (Left(l), _) | (_, Left(l)) => Left(l),
+            (Right(t), Right(r)) => Right(f(t, r)),
+        }
+    }
+}
+
+impl<L, R> Bifunctor for Either<L, R> {
+    type Inner1 = L;
+
+    type Inner2 = R;
+
+    type Outter<B, C> = Either<B, C>;
+
+    fn bimap<F1, F2, B, C>(self, mut f1: F1, mut f2: F2) -> Self::Outter<B, C>
+    where
+        F1: FnMut(Self::Inner1) -> B,
+        F2: FnMut(Self::Inner2) -> C,
+    {
+        match self {
+            Left(l) => Left(f1(l)),
+            Right(r) => Right(f2(r)),
+        }
+    }
+}
+
+impl<L, R> Foldable for Either<L, R> {
+    type Inner = R;
+
+    type Outter<B> = Either<L, B>;
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.right().into_iter().fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.right().into_iter().rev().fold(start, f)
+    }
+}
+
+impl<L, R> Traverse for Either<L, R> {
+    fn traverse<G, B, F>(self, mut f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = B>,
+        F: FnMut(<Self as Functor>::Inner) -> G,
+    {
+        match self {
+            Left(l) => G::pure_acc(Left(l)),
+            Right(r) => f(r).fmap(Right),
+        }
+    }
+
+    fn flat_traverse<G, B, F>(self, mut f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = <Self as Functor>::Outter<B>>,
+        F: FnMut(<Self as Functor>::Inner) -> G,
+    {
+        match self {
+            Left(l) => G::pure_acc(Left(l)),
+            Right(r) => f(r).fmap(|b| b),
+        }
+    }
+}
+
+impl<L, R> SemigroupK for Either<L, R> {
+    type Inner = R;
+    type Outter<B> = Either<L, B>;
+
+    fn combine_k(self, other: Self::Outter<Self::Inner>) -> Self::Outter<Self::Inner> {
+        match self {
+            Left(_) => other,
+            right => right,
+        }
+    }
+}
+
+impl<L, R: Semigroup> Semigroup for Either<L, R> {
+    fn combine(self, b: Self) -> Self {
+        match (self, b) {
+            (a @ Left(_), _) => a,
+            (_, b @ Left(_)) => b,
+            (Right(a), Right(b)) => Right(a.combine(b)),
+        }
+    }
+}
+
+impl<L, R: Monoid> Monoid for Either<L, R> {
+    fn empty() -> Self {
+        Right(R::empty())
+    }
+}
+
+#[cfg(test)]
+impl<L: quickcheck::Arbitrary, R: quickcheck::Arbitrary> quickcheck::Arbitrary for Either<L, R> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Result::<R, L>::arbitrary(g).into()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            Result::<R, L>::from(self.clone())
+                .shrink()
+                .map(Either::from),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swap_exchanges_sides() {
+        assert_eq!(Either::<u64, &str>::Left(1).swap(), Right(1));
+        assert_eq!(Either::<u64, &str>::Right("a").swap(), Left("a"));
+    }
+
+    #[test]
+    fn left_map_only_touches_left() {
+        assert_eq!(Either::<u64, u64>::Left(1).left_map(|l| l + 1), Left(2));
+        assert_eq!(Either::<u64, u64>::Right(1).left_map(|l| l + 1), Right(1));
+    }
+
+    #[test]
+    fn fold_and_merge() {
+        let left: Either<u64, &str> = Left(3);
+        let right: Either<u64, &str> = Right("four");
+        assert_eq!(left.fold(|l| l as usize, |r| r.len()), 3);
+        assert_eq!(right.fold(|l| l as usize, |r| r.len()), 4);
+        assert_eq!(Either::<u64, u64>::Left(5).merge(), 5);
+        assert_eq!(Either::<u64, u64>::Right(6).merge(), 6);
+    }
+
+    #[test]
+    fn result_conversions() {
+        assert_eq!(Either::from(Result::<u64, &str>::Ok(1)), Right(1));
+        assert_eq!(Either::from(Result::<u64, &str>::Err("e")), Left("e"));
+        assert_eq!(Result::from(Either::<&str, u64>::Right(1)), Ok(1));
+        assert_eq!(Result::from(Either::<&str, u64>::Left("e")), Err("e"));
+    }
+
+    #[test]
+    fn flat_map_is_right_biased() {
+        let decrement = |x: u64| -> Either<String, u64> {
+            match x.checked_sub(1) {
+                Some(x) => Right(x),
+                None => Left("underflow".to_string()),
+            }
+        };
+        assert_eq!(Right(8).flat_map(decrement).flat_map(decrement), Right(6));
+        assert_eq!(
+            Right(1).flat_map(decrement).flat_map(decrement),
+            Left("underflow".into())
+        );
+    }
+
+    #[test]
+    fn sequence_vec_of_either() {
+        let values: Vec<Either<&str, u64>> = vec![Right(1), Right(2)];
+        assert_eq!(values.sequence(), Right(vec![1, 2]));
+        let values: Vec<Either<&str, u64>> = vec![Right(1), Left("a"), Left("b")];
+        assert_eq!(values.sequence(), Left("a"));
+    }
+
+    #[test]
+    fn semigroup_k_is_first_right() {
+        assert_eq!(Either::<(), u64>::Left(()).combine_k(Right(2)), Right(2));
+        assert_eq!(Either::<(), u64>::Right(1).combine_k(Right(2)), Right(1));
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use super::*;
+
+    type EitherUsize = Either<String, usize>;
+
+    fn reject_multiples_of_three(a: usize, b: usize) -> EitherUsize {
+        if a.is_multiple_of(3) {
+            Left(format!("{} rejected", a))
+        } else {
+            Right(b)
+        }
+    }
+
+    functor_identity!(EitherUsize);
+    functor_composition!(EitherUsize);
+    apply_composition!(EitherUsize);
+    applicative_identity!(EitherUsize);
+    applicative_homomorphism!(EitherUsize);
+    flat_map_associativity!(EitherUsize by reject_multiples_of_three);
+    monad_left_identity!(EitherUsize by reject_multiples_of_three);
+    monad_right_identity!(EitherUsize);
+    bifunctor_identity!(EitherUsize);
+    bifunctor_composition!(EitherUsize);
+    foldable_consistent_with_right!(EitherUsize);
+    semigroup_k_associativity!(EitherUsize);
+}
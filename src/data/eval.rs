@@ -0,0 +1,485 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::core::prelude::*;
+
+/// A lazily evaluated value, in the style of Cats' `Eval`.
+///
+/// Evaluation is trampolined: `map`, `flat_map` and `defer` push their
+/// continuations on a heap stack, so arbitrarily deep chains run in constant
+/// call stack. Cloning shares the computation, which is what lets `later`
+/// and `memoize` compute their value only once.
+///
+/// The `Functor`..`Monad` instances force the value: those traits take
+/// closures that may borrow, so `fmap`, `apply` and `flat_map` can't store
+/// them to run later. The inherent `map` and `flat_map` take precedence in
+/// method calls and stay lazy.
+pub struct Eval<A>(Repr<A>);
+
+enum Repr<A> {
+    Now(A),
+    /// The erased computation, and the function recovering its type.
+    Lazy(Rc<dyn Thunk>, fn(Value) -> A),
+}
+
+impl<A: Clone> Clone for Eval<A> {
+    fn clone(&self) -> Self {
+        match &self.0 {
+            Repr::Now(a) => Eval(Repr::Now(a.clone())),
+            Repr::Lazy(thunk, cast) => Eval(Repr::Lazy(thunk.clone(), *cast)),
+        }
+    }
+}
+
+impl<A> Eval<A> {
+    /// An already computed value.
+    pub fn now(value: A) -> Self {
+        Eval(Repr::Now(value))
+    }
+
+    /// Runs the computation.
+    pub fn value(self) -> A {
+        match self.0 {
+            Repr::Now(a) => a,
+            Repr::Lazy(thunk, cast) => cast(run(thunk)),
+        }
+    }
+}
+
+impl<A: 'static> Eval<A> {
+    fn lazy(thunk: Rc<dyn Thunk>) -> Self {
+        Eval(Repr::Lazy(thunk, downcast::<A>))
+    }
+
+    /// Computed again every time it's needed.
+    pub fn always(f: impl Fn() -> A + 'static) -> Self {
+        Eval::lazy(Rc::new(Always(Box::new(f))))
+    }
+}
+
+/// Values are handed out again on every run, so building lazily on top of an
+/// `Eval` needs `A: Clone`.
+impl<A: Clone + 'static> Eval<A> {
+    /// Computed the first time it's needed and memoized after that.
+    pub fn later(f: impl FnOnce() -> A + 'static) -> Self {
+        Eval::lazy(Rc::new(Later(RefCell::new(LaterState::Pending(Box::new(
+            f,
+        ))))))
+    }
+
+    /// Builds the `Eval` itself lazily. This is what makes recursive
+    /// definitions stack-safe.
+    pub fn defer(f: impl Fn() -> Eval<A> + 'static) -> Self {
+        Eval::lazy(Rc::new(Defer(Box::new(move || f().into_thunk()))))
+    }
+
+    pub fn map<B: 'static>(self, f: impl Fn(A) -> B + 'static) -> Eval<B> {
+        let cont: Cont = Rc::new(move |value| ready(f(downcast::<A>(value))));
+        Eval::lazy(Rc::new(Bind(Some(self.into_thunk()), cont)))
+    }
+
+    pub fn flat_map<B: Clone + 'static>(self, f: impl Fn(A) -> Eval<B> + 'static) -> Eval<B> {
+        let cont: Cont = Rc::new(move |value| f(downcast::<A>(value)).into_thunk());
+        Eval::lazy(Rc::new(Bind(Some(self.into_thunk()), cont)))
+    }
+
+    /// Caches the value the first time it's computed, like `later`.
+    pub fn memoize(self) -> Self {
+        match self.0 {
+            Repr::Now(_) => self,
+            Repr::Lazy(..) => Eval::later(move || self.value()),
+        }
+    }
+
+    fn into_thunk(self) -> Rc<dyn Thunk> {
+        match self.0 {
+            Repr::Now(a) => Rc::new(Now(a)),
+            Repr::Lazy(thunk, _) => thunk,
+        }
+    }
+}
+
+impl<A> Functor for Eval<A> {
+    type Inner = A;
+    type Outter<B> = Eval<B>;
+
+    /// Forces `self`; use the inherent `map` to stay lazy.
+    fn fmap<F, B>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        Eval::now(f(self.value()))
+    }
+}
+
+impl<A> Apply for Eval<A> {
+    /// Forces both `self` and `f`.
+    fn apply<B, F>(self, f: Self::Outter<F>) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        Eval::now((f.value())(self.value()))
+    }
+}
+
+impl<A> Applicative for Eval<A> {
+    fn pure(value: Self::Inner) -> Self::Outter<Self::Inner> {
+        Eval::now(value)
+    }
+}
+
+impl<A> FlatMap for Eval<A> {
+    /// Forces `self`; use the inherent `flat_map` to stay lazy.
+    fn flat_map<B, F>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> Self::Outter<B>,
+    {
+        f(self.value())
+    }
+}
+
+impl<A> Monad for Eval<A> {}
+
+impl<A, T> TraverseApplicative<T> for Eval<A> {
+    fn pure_acc(value: T) -> Self::Outter<T> {
+        Eval::now(value)
+    }
+
+    /// Forces both `acc` and `elem`, like the other instances.
+    fn accumulate<F>(acc: Self::Outter<T>, elem: Self, mut f: F) -> Self::Outter<T>
+    where
+        F: FnMut(T, Self::Inner) -> T,
+    {
+        Eval::now(f(acc.value(), elem.value()))
+    }
+}
+
+type Value = Box<dyn Any>;
+type Cont = Rc<dyn Fn(Value) -> Rc<dyn Thunk>>;
+
+enum Step {
+    Done(Value),
+    Continue(Rc<dyn Thunk>),
+    Bind(Rc<dyn Thunk>, Cont),
+}
+
+/// A node of the computation with its type erased, so the trampoline can
+/// drive chains whose intermediate types differ.
+trait Thunk {
+    fn step(&self) -> Step;
+
+    /// Hands over the node this one is built on, so chains can be dropped
+    /// without recursion.
+    fn detach(&mut self) -> Option<Rc<dyn Thunk>> {
+        None
+    }
+}
+
+fn run(thunk: Rc<dyn Thunk>) -> Value {
+    let mut current = thunk;
+    let mut conts: Vec<Cont> = Vec::new();
+    loop {
+        match current.step() {
+            Step::Done(value) => match conts.pop() {
+                Some(cont) => current = cont(value),
+                None => return value,
+            },
+            Step::Continue(next) => current = next,
+            Step::Bind(source, cont) => {
+                conts.push(cont);
+                current = source;
+            }
+        }
+    }
+}
+
+fn downcast<A: 'static>(value: Value) -> A {
+    *value
+        .downcast()
+        .unwrap_or_else(|_| unreachable!("an Eval always produces its own type"))
+}
+
+/// A value computed by a continuation, consumed by the single `run` that
+/// created it.
+fn ready<A: 'static>(value: A) -> Rc<dyn Thunk> {
+    Rc::new(Ready(RefCell::new(Some(Box::new(value)))))
+}
+
+struct Ready(RefCell<Option<Value>>);
+
+impl Thunk for Ready {
+    fn step(&self) -> Step {
+        Step::Done(
+            self.0
+                .borrow_mut()
+                .take()
+                .expect("a ready value is run once"),
+        )
+    }
+}
+
+struct Now<A>(A);
+
+impl<A: Clone + 'static> Thunk for Now<A> {
+    fn step(&self) -> Step {
+        Step::Done(Box::new(self.0.clone()))
+    }
+}
+
+enum LaterState<A> {
+    Pending(Box<dyn FnOnce() -> A>),
+    Running,
+    Done(A),
+    /// The computation panicked, so there is no value to hand out.
+    Poisoned,
+}
+
+struct Later<A>(RefCell<LaterState<A>>);
+
+/// Marks a `Later` as poisoned if its computation unwinds, instead of leaving
+/// it `Running`.
+struct PoisonOnUnwind<'a, A>(&'a RefCell<LaterState<A>>);
+
+impl<A> Drop for PoisonOnUnwind<'_, A> {
+    fn drop(&mut self) {
+        *self.0.borrow_mut() = LaterState::Poisoned;
+    }
+}
+
+impl<A: Clone + 'static> Thunk for Later<A> {
+    fn step(&self) -> Step {
+        let state = std::mem::replace(&mut *self.0.borrow_mut(), LaterState::Running);
+        let value = match state {
+            LaterState::Pending(f) => {
+                let guard = PoisonOnUnwind(&self.0);
+                let value = f();
+                std::mem::forget(guard);
+                value
+            }
+            LaterState::Done(value) => value,
+            LaterState::Running => panic!("Eval::later was forced while computing itself"),
+            LaterState::Poisoned => panic!("Eval::later panicked while computing its value"),
+        };
+        *self.0.borrow_mut() = LaterState::Done(value.clone());
+        Step::Done(Box::new(value))
+    }
+}
+
+struct Always<A>(Box<dyn Fn() -> A>);
+
+impl<A: 'static> Thunk for Always<A> {
+    fn step(&self) -> Step {
+        Step::Done(Box::new((self.0)()))
+    }
+}
+
+struct Defer(Box<dyn Fn() -> Rc<dyn Thunk>>);
+
+impl Thunk for Defer {
+    fn step(&self) -> Step {
+        Step::Continue((self.0)())
+    }
+}
+
+/// The source is only `None` while being dropped.
+struct Bind(Option<Rc<dyn Thunk>>, Cont);
+
+impl Thunk for Bind {
+    fn step(&self) -> Step {
+        let source = self
+            .0
+            .clone()
+            .expect("a Bind keeps its source until dropped");
+        Step::Bind(source, self.1.clone())
+    }
+
+    fn detach(&mut self) -> Option<Rc<dyn Thunk>> {
+        self.0.take()
+    }
+}
+
+impl Drop for Bind {
+    fn drop(&mut self) {
+        let mut next = self.0.take();
+        while let Some(mut thunk) = next {
+            next = Rc::get_mut(&mut thunk).and_then(|thunk| thunk.detach());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn counted<A>(calls: &Rc<Cell<u32>>, value: A) -> impl Fn() -> A + Clone
+    where
+        A: Clone,
+    {
+        let calls = calls.clone();
+        move || {
+            calls.set(calls.get() + 1);
+            value.clone()
+        }
+    }
+
+    #[test]
+    fn later_is_computed_once() {
+        let calls = Rc::new(Cell::new(0));
+        let eval = Eval::later(counted(&calls, 42));
+        assert_eq!(calls.get(), 0);
+        assert_eq!(eval.clone().value(), 42);
+        assert_eq!(eval.value(), 42);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "panicked while computing its value")]
+    fn later_is_poisoned_by_a_panic() {
+        let eval: Eval<u32> = Eval::later(|| panic!("boom"));
+        let forced = eval.clone();
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| forced.value())).is_err());
+        eval.value();
+    }
+
+    #[test]
+    fn always_is_computed_every_time() {
+        let calls = Rc::new(Cell::new(0));
+        let eval = Eval::always(counted(&calls, 42));
+        assert_eq!(eval.clone().value(), 42);
+        assert_eq!(eval.value(), 42);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn memoize_caches_an_always() {
+        let calls = Rc::new(Cell::new(0));
+        let eval = Eval::always(counted(&calls, 1)).map(|a| a + 1).memoize();
+        assert_eq!(calls.get(), 0);
+        assert_eq!(eval.clone().value(), 2);
+        assert_eq!(eval.value(), 2);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn a_shared_later_is_computed_once_across_binds() {
+        let calls = Rc::new(Cell::new(0));
+        let shared = Eval::later(counted(&calls, 10));
+        let other = shared.clone();
+        let sum = shared.flat_map(move |a| other.clone().map(move |b| a + b));
+        assert_eq!(sum.value(), 20);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[quickcheck]
+    fn map_agrees_with_the_function(x: u32) -> bool {
+        Eval::now(x).map(|a| a.wrapping_mul(3)).value() == x.wrapping_mul(3)
+    }
+
+    #[quickcheck]
+    fn flat_map_agrees_with_the_instance(x: u32) -> bool {
+        let lazy = Eval::now(x).flat_map(|a| Eval::later(move || a.to_string()));
+        let strict = FlatMap::flat_map(Eval::now(x), |a| Eval::later(move || a.to_string()));
+        lazy.value() == strict.value()
+    }
+
+    #[test]
+    fn instances() {
+        assert_eq!(Eval::now(3).fmap(|a| a * 2).value(), 6);
+        assert_eq!(Eval::now(3).apply(Eval::now(|a| a + 1)).value(), 4);
+        assert_eq!(Eval::pure(3).value(), 3);
+        assert_eq!(
+            vec![Eval::now(1), Eval::later(|| 2)].sequence().value(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn folding_into_an_eval_is_lazy_until_value() {
+        let calls = Rc::new(Cell::new(0));
+        let sum = vec![1u32, 2, 3].fold_left(Eval::now(0), |acc, a| {
+            let calls = calls.clone();
+            acc.flat_map(move |b| {
+                let calls = calls.clone();
+                Eval::always(move || {
+                    calls.set(calls.get() + 1);
+                    a + b
+                })
+            })
+        });
+        assert_eq!(calls.get(), 0);
+        assert_eq!(sum.value(), 6);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn fold_m_over_eval_forces_every_step() {
+        let calls = Rc::new(Cell::new(0));
+        let sum = vec![1u32, 2, 3].fold_m(0, |b, a| {
+            let calls = calls.clone();
+            Eval::always(move || {
+                calls.set(calls.get() + 1);
+                a + b
+            })
+        });
+        assert_eq!(calls.get(), 2);
+        assert_eq!(sum.value(), 6);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn long_map_chains_are_stack_safe() {
+        let eval = (0..1_000_000).fold(Eval::now(0u64), |eval, _| eval.map(|a| a + 1));
+        assert_eq!(eval.value(), 1_000_000);
+    }
+
+    #[test]
+    fn a_million_nested_binds_are_stack_safe() {
+        let eval = (0..1_000_000).fold(Eval::now(0u64), |eval, _| {
+            eval.flat_map(|a| Eval::now(a + 1))
+        });
+        assert_eq!(eval.value(), 1_000_000);
+    }
+
+    fn count_down(n: u64) -> Eval<u64> {
+        Eval::now(n).flat_map(|n| {
+            if n == 0 {
+                Eval::now(0)
+            } else {
+                count_down(n - 1).map(|a| a + 1)
+            }
+        })
+    }
+
+    #[test]
+    fn deep_recursion_through_flat_map_is_stack_safe() {
+        assert_eq!(count_down(1_000_000).value(), 1_000_000);
+    }
+
+    fn is_even(n: u64) -> Eval<bool> {
+        Eval::defer(move || {
+            if n == 0 {
+                Eval::now(true)
+            } else {
+                is_odd(n - 1)
+            }
+        })
+    }
+
+    fn is_odd(n: u64) -> Eval<bool> {
+        Eval::defer(move || {
+            if n == 0 {
+                Eval::now(false)
+            } else {
+                is_even(n - 1)
+            }
+        })
+    }
+
+    #[test]
+    fn mutual_recursion_through_defer_is_stack_safe() {
+        assert!(is_even(1_000_000).value());
+        assert!(is_odd(999_999).value());
+    }
+}
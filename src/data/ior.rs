@@ -0,0 +1,330 @@
+use crate::core::prelude::*;
+use crate::data::either::Either;
+use crate::kernel::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Ior<L, R> {
+    Left(L),
+    Right(R),
+    Both(L, R),
+}
+
+impl<L, R> Ior<L, R> {
+    pub fn from_options(left: Option<L>, right: Option<R>) -> Option<Self> {
+        match (left, right) {
+            (Some(l), Some(r)) => Some(Ior::Both(l, r)),
+            (Some(l), None) => Some(Ior::Left(l)),
+            (None, Some(r)) => Some(Ior::Right(r)),
+            (None, None) => None,
+        }
+    }
+
+    pub fn is_left(&self) -> bool {
+        matches!(self, Ior::Left(_))
+    }
+
+    pub fn is_right(&self) -> bool {
+        matches!(self, Ior::Right(_))
+    }
+
+    pub fn is_both(&self) -> bool {
+        matches!(self, Ior::Both(_, _))
+    }
+
+    pub fn left(self) -> Option<L> {
+        self.pad().0
+    }
+
+    pub fn right(self) -> Option<R> {
+        self.pad().1
+    }
+
+    pub fn pad(self) -> (Option<L>, Option<R>) {
+        match self {
+            Ior::Left(l) => (Some(l), None),
+            Ior::Right(r) => (None, Some(r)),
+            Ior::Both(l, r) => (Some(l), Some(r)),
+        }
+    }
+
+    pub fn swap(self) -> Ior<R, L> {
+        match self {
+            Ior::Left(l) => Ior::Right(l),
+            Ior::Right(r) => Ior::Left(r),
+            Ior::Both(l, r) => Ior::Both(r, l),
+        }
+    }
+
+    pub fn fold<C, FL, FR, FB>(self, fl: FL, fr: FR, fb: FB) -> C
+    where
+        FL: FnOnce(L) -> C,
+        FR: FnOnce(R) -> C,
+        FB: FnOnce(L, R) -> C,
+    {
+        match self {
+            Ior::Left(l) => fl(l),
+            Ior::Right(r) => fr(r),
+            Ior::Both(l, r) => fb(l, r),
+        }
+    }
+
+    /// Drops the left side of a `Both`, keeping only the result.
+    pub fn to_either(self) -> Either<L, R> {
+        match self {
+            Ior::Left(l) => Either::Left(l),
+            Ior::Right(r) | Ior::Both(_, r) => Either::Right(r),
+        }
+    }
+}
+
+impl<L: Semigroup, R> Ior<L, R> {
+    fn add_left(self, left: L) -> Self {
+        match self {
+            Ior::Left(l) => Ior::Left(left.combine(l)),
+            Ior::Right(r) => Ior::Both(left, r),
+            Ior::Both(l, r) => Ior::Both(left.combine(l), r),
+        }
+    }
+}
+
+impl<L, R> Functor for Ior<L, R> {
+    type Inner = R;
+    type Outter<B> = Ior<L, B>;
+
+    fn fmap<F, B>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        match self {
+            Ior::Left(l) => Ior::Left(l),
+            Ior::Right(r) => Ior::Right(f(r)),
+            Ior::Both(l, r) => Ior::Both(l, f(r)),
+        }
+    }
+}
+
+impl<L: Semigroup, R> Apply for Ior<L, R> {
+    fn apply<B, F>(self, f: Self::Outter<F>) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        match f {
+            Ior::Left(l) => Ior::Left(l),
+            Ior::Right(f) => self.fmap(f),
+            Ior::Both(l, f) => self.fmap(f).add_left(l),
+        }
+    }
+}
+
+impl<L: Semigroup, R> Applicative for Ior<L, R> {
+    fn pure(value: Self::Inner) -> Self::Outter<Self::Inner> {
+        Ior::Right(value)
+    }
+}
+
+impl<L: Semigroup, R> FlatMap for Ior<L, R> {
+    fn flat_map<B, F>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> Self::Outter<B>,
+    {
+        match self {
+            Ior::Left(l) => Ior::Left(l),
+            Ior::Right(r) => f(r),
+            Ior::Both(l, r) => f(r).add_left(l),
+        }
+    }
+}
+
+impl<L: Semigroup, R> Monad for Ior<L, R> {}
+
+impl<L: Semigroup, R, T> TraverseApplicative<T> for Ior<L, R> {
+    fn pure_acc(value: T) -> Self::Outter<T> {
+        Ior::Right(value)
+    }
+
+    fn accumulate<F>(acc: Self::Outter<T>, elem: Self, mut f: F) -> Self::Outter<T>
+    where
+        F: FnMut(T, Self::Inner) -> T,
+    {
+        let (acc_left, t) = match acc {
+            Ior::Left(l) => return Ior::Left(l),
+            Ior::Right(t) => (None, t),
+            Ior::Both(l, t) => (Some(l), t),
+        };
+        let combined = match elem {
+            Ior::Left(l) => Ior::Left(l),
+            Ior::Right(r) => Ior::Right(f(t, r)),
+            Ior::Both(l, r) => Ior::Both(l, f(t, r)),
+        };
+        match acc_left {
+            Some(l) => combined.add_left(l),
+            None => combined,
+        }
+    }
+}
+
+impl<L, R> Bifunctor for Ior<L, R> {
+    type Inner1 = L;
+
+    type Inner2 = R;
+
+    type Outter<B, C> = Ior<B, C>;
+
+    fn bimap<F1, F2, B, C>(self, mut f1: F1, mut f2: F2) -> Self::Outter<B, C>
+    where
+        F1: FnMut(Self::Inner1) -> B,
+        F2: FnMut(Self::Inner2) -> C,
+    {
+        match self {
+            Ior::Left(l) => Ior::Left(f1(l)),
+            Ior::Right(r) => Ior::Right(f2(r)),
+            Ior::Both(l, r) => Ior::Both(f1(l), f2(r)),
+        }
+    }
+}
+
+impl<L, R> Foldable for Ior<L, R> {
+    type Inner = R;
+
+    type Outter<B> = Ior<L, B>;
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.right().into_iter().fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.right().into_iter().rev().fold(start, f)
+    }
+}
+
+impl<L: Semigroup, R: Semigroup> Semigroup for Ior<L, R> {
+    fn combine(self, b: Self) -> Self {
+        let (l1, r1) = self.pad();
+        let (l2, r2) = b.pad();
+        // both sides can't be None at once since neither Ior is empty
+        Ior::from_options(l1.combine(l2), r1.combine(r2)).unwrap()
+    }
+}
+
+#[cfg(test)]
+impl<L: quickcheck::Arbitrary, R: quickcheck::Arbitrary> quickcheck::Arbitrary for Ior<L, R> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let left = Option::<L>::arbitrary(g);
+        let right = Option::<R>::arbitrary(g);
+        Ior::from_options(left, right).unwrap_or_else(|| Ior::Right(R::arbitrary(g)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checked_half(n: u64) -> Ior<Vec<String>, u64> {
+        if n % 2 == 1 {
+            Ior::Both(vec![format!("{} rounded down", n)], n / 2)
+        } else if n == 0 {
+            Ior::Left(vec!["zero".to_string()])
+        } else {
+            Ior::Right(n / 2)
+        }
+    }
+
+    #[test]
+    fn flat_map_accumulates_warnings() {
+        let result = Ior::Right(14).flat_map(checked_half).flat_map(checked_half);
+        assert_eq!(result, Ior::Both(vec!["7 rounded down".to_string()], 3));
+
+        let result = Ior::Both(vec!["start".to_string()], 7)
+            .flat_map(checked_half)
+            .flat_map(checked_half);
+        assert_eq!(
+            result,
+            Ior::Both(
+                vec![
+                    "start".to_string(),
+                    "7 rounded down".to_string(),
+                    "3 rounded down".to_string()
+                ],
+                1
+            )
+        );
+    }
+
+    #[test]
+    fn flat_map_short_circuits_on_left() {
+        let mut called = false;
+        let result = Ior::Both(vec!["warning".to_string()], 0)
+            .flat_map(checked_half)
+            .flat_map(|n| {
+                called = true;
+                Ior::Right(n)
+            });
+        assert_eq!(
+            result,
+            Ior::Left(vec!["warning".to_string(), "zero".to_string()])
+        );
+        assert!(!called);
+    }
+
+    #[test]
+    fn apply_accumulates_both_sides() {
+        let value: Ior<String, u64> = Ior::Both("a".to_string(), 2);
+        let f: Ior<String, fn(u64) -> u64> = Ior::Both("b".to_string(), |x| x * 10);
+        assert_eq!(value.apply(f), Ior::Both("ba".to_string(), 20));
+    }
+
+    #[test]
+    fn sequence_vec_of_ior() {
+        let values = vec![
+            Ior::Right(1),
+            Ior::Both("a".to_string(), 2),
+            Ior::Both("b".to_string(), 3),
+        ];
+        assert_eq!(
+            values.sequence(),
+            Ior::Both("ab".to_string(), vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn semigroup_combines_each_side() {
+        let a: Ior<String, String> = Ior::Left("l".to_string());
+        let b: Ior<String, String> = Ior::Right("r".to_string());
+        assert_eq!(a.combine(b), Ior::Both("l".to_string(), "r".to_string()));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Ior::<u64, u64>::from_options(None, None), None);
+        assert_eq!(Ior::Both(1, 2).pad(), (Some(1), Some(2)));
+        assert_eq!(Ior::<u64, u64>::Both(1, 2).to_either(), Either::Right(2));
+        assert_eq!(Ior::<u64, u64>::Left(1).to_either(), Either::Left(1));
+        assert_eq!(Ior::<u64, &str>::Both(1, "a").swap(), Ior::Both("a", 1));
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use super::*;
+
+    type IorUsize = Ior<String, usize>;
+
+    fn warn_by_remainder(a: usize, b: usize) -> IorUsize {
+        match a % 3 {
+            0 => Ior::Left(format!("{} rejected;", a)),
+            1 => Ior::Both(format!("{} warned;", a), b),
+            _ => Ior::Right(b),
+        }
+    }
+
+    functor_identity!(IorUsize);
+    functor_composition!(IorUsize);
+    apply_composition!(IorUsize);
+    apply_consistent_with_flat_map!(IorUsize);
+    flat_map_associativity!(IorUsize by warn_by_remainder);
+    monad_left_identity!(IorUsize by warn_by_remainder);
+    monad_right_identity!(IorUsize);
+    bifunctor_identity!(IorUsize);
+    bifunctor_composition!(IorUsize);
+    foldable_consistent_with_right!(IorUsize);
+}
@@ -0,0 +1,13 @@
+pub mod either;
+pub mod eval;
+pub mod ior;
+pub mod non_empty_vec;
+pub mod validated;
+
+pub mod prelude {
+    pub use super::either::*;
+    pub use super::eval::*;
+    pub use super::ior::*;
+    pub use super::non_empty_vec::*;
+    pub use super::validated::*;
+}
@@ -0,0 +1,331 @@
+use crate::core::prelude::*;
+use crate::kernel::prelude::Semigroup;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonEmptyVec<A> {
+    pub head: A,
+    pub tail: Vec<A>,
+}
+
+impl<A> NonEmptyVec<A> {
+    pub fn new(head: A, tail: Vec<A>) -> Self {
+        NonEmptyVec { head, tail }
+    }
+
+    pub fn one(head: A) -> Self {
+        NonEmptyVec::new(head, Vec::new())
+    }
+
+    pub fn from_vec(values: Vec<A>) -> Option<Self> {
+        let mut values = values.into_iter();
+        let head = values.next()?;
+        Some(NonEmptyVec::new(head, values.collect()))
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.tail.len() + 1
+    }
+
+    pub fn last(&self) -> &A {
+        self.tail.last().unwrap_or(&self.head)
+    }
+
+    pub fn push(&mut self, value: A) {
+        self.tail.push(value);
+    }
+
+    /// Appends `b` in place; `NonEmptyVec` has no `Monoid::empty` to swap
+    /// through.
+    pub fn combine_assign(&mut self, b: Self) {
+        self.tail.reserve(b.len());
+        self.tail.push(b.head);
+        self.tail.extend(b.tail);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &A> {
+        std::iter::once(&self.head).chain(self.tail.iter())
+    }
+
+    pub fn into_vec(self) -> Vec<A> {
+        let mut values = Vec::with_capacity(self.len());
+        values.push(self.head);
+        values.extend(self.tail);
+        values
+    }
+}
+
+impl<A> From<NonEmptyVec<A>> for Vec<A> {
+    fn from(values: NonEmptyVec<A>) -> Self {
+        values.into_vec()
+    }
+}
+
+impl<A> IntoIterator for NonEmptyVec<A> {
+    type Item = A;
+    type IntoIter = std::iter::Chain<std::iter::Once<A>, std::vec::IntoIter<A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        std::iter::once(self.head).chain(self.tail)
+    }
+}
+
+impl<A> Functor for NonEmptyVec<A> {
+    type Inner = A;
+    type Outter<B> = NonEmptyVec<B>;
+
+    fn fmap<F, B>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        let head = f(self.head);
+        NonEmptyVec::new(head, self.tail.fmap(f))
+    }
+}
+
+impl<A: Clone> Apply for NonEmptyVec<A> {
+    fn apply<B, F>(self, f: Self::Outter<F>) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        let applied = self.into_vec().apply(f.into_vec());
+        NonEmptyVec::from_vec(applied).expect("applying non-empty vecs is never empty")
+    }
+}
+
+impl<A: Clone> Applicative for NonEmptyVec<A> {
+    fn pure(value: Self::Inner) -> Self::Outter<Self::Inner> {
+        NonEmptyVec::one(value)
+    }
+}
+
+impl<A: Clone> FlatMap for NonEmptyVec<A> {
+    fn flat_map<B, F>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> Self::Outter<B>,
+    {
+        let head = f(self.head);
+        self.tail.into_iter().fold(head, |acc, a| acc.combine(f(a)))
+    }
+}
+
+impl<A: Clone> Monad for NonEmptyVec<A> {}
+
+impl<A: Clone, T: Clone> TraverseApplicative<T> for NonEmptyVec<A> {
+    fn pure_acc(value: T) -> Self::Outter<T> {
+        NonEmptyVec::one(value)
+    }
+
+    fn accumulate<F>(acc: Self::Outter<T>, elem: Self, f: F) -> Self::Outter<T>
+    where
+        F: FnMut(T, Self::Inner) -> T,
+    {
+        let combined = Vec::accumulate(acc.into_vec(), elem.into_vec(), f);
+        NonEmptyVec::from_vec(combined).expect("combining non-empty vecs is never empty")
+    }
+}
+
+impl<A> Foldable for NonEmptyVec<A> {
+    type Inner = A;
+
+    type Outter<B> = NonEmptyVec<B>;
+
+    fn fold_left<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_iter().fold(start, f)
+    }
+
+    fn fold_right<B>(self, start: B, f: impl FnMut(B, Self::Inner) -> B) -> B {
+        self.into_vec().into_iter().rev().fold(start, f)
+    }
+
+    fn try_fold_left<B, E>(
+        self,
+        start: B,
+        f: impl FnMut(B, Self::Inner) -> Result<B, E>,
+    ) -> Result<B, E> {
+        self.into_iter().try_fold(start, f)
+    }
+}
+
+impl<A> Reducible for NonEmptyVec<A> {
+    fn reduce_left_to<B>(
+        self,
+        f: impl FnOnce(Self::Inner) -> B,
+        g: impl FnMut(B, Self::Inner) -> B,
+    ) -> B {
+        self.tail.into_iter().fold(f(self.head), g)
+    }
+
+    fn reduce_right_to<B>(
+        self,
+        f: impl FnOnce(Self::Inner) -> B,
+        g: impl FnMut(B, Self::Inner) -> B,
+    ) -> B {
+        let mut values = self.into_vec();
+        let last = values.pop().expect("a NonEmptyVec is never empty");
+        values.into_iter().rev().fold(f(last), g)
+    }
+}
+
+impl<A> Traverse for NonEmptyVec<A> {
+    fn traverse<G, B, F>(self, mut f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = B>,
+        F: FnMut(<Self as Functor>::Inner) -> G,
+    {
+        let start = f(self.head).fmap(NonEmptyVec::one);
+        self.tail.into_iter().map(f).fold(start, |acc, g| {
+            G::accumulate(acc, g, |mut values, b| {
+                values.push(b);
+                values
+            })
+        })
+    }
+
+    fn flat_traverse<G, B, F>(self, mut f: F) -> G::Outter<<Self as Functor>::Outter<B>>
+    where
+        G: TraverseApplicative<<Self as Functor>::Outter<B>, Inner = <Self as Functor>::Outter<B>>,
+        F: FnMut(<Self as Functor>::Inner) -> G,
+    {
+        let start = f(self.head).fmap(|b| b);
+        self.tail
+            .into_iter()
+            .map(f)
+            .fold(start, |acc, g| G::accumulate(acc, g, Semigroup::combine))
+    }
+}
+
+impl<A> SemigroupK for NonEmptyVec<A> {
+    type Inner = A;
+    type Outter<B> = NonEmptyVec<B>;
+
+    fn combine_k(self, other: Self::Outter<Self::Inner>) -> Self::Outter<Self::Inner> {
+        self.combine(other)
+    }
+}
+
+impl<A> Semigroup for NonEmptyVec<A> {
+    fn combine(mut self, b: Self) -> Self {
+        self.combine_assign(b);
+        self
+    }
+}
+
+#[cfg(test)]
+impl<A: quickcheck::Arbitrary> quickcheck::Arbitrary for NonEmptyVec<A> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        NonEmptyVec::new(A::arbitrary(g), Vec::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.clone()
+                .into_vec()
+                .shrink()
+                .filter_map(NonEmptyVec::from_vec),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combine_keeps_order() {
+        let left = NonEmptyVec::new(1, vec![2]);
+        let right = NonEmptyVec::new(3, vec![4, 5]);
+        assert_eq!(left.combine(right).into_vec(), vec![1, 2, 3, 4, 5]);
+
+        let mut assigned = NonEmptyVec::one(1);
+        assigned.combine_assign(NonEmptyVec::new(2, vec![3]));
+        assert_eq!(assigned.into_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn from_vec() {
+        assert_eq!(NonEmptyVec::<u64>::from_vec(vec![]), None);
+        assert_eq!(
+            NonEmptyVec::from_vec(vec![1, 2, 3]),
+            Some(NonEmptyVec::new(1, vec![2, 3]))
+        );
+    }
+
+    #[test]
+    fn flat_map_concatenates() {
+        let values = NonEmptyVec::new(1, vec![2]);
+        assert_eq!(
+            values.flat_map(|x| NonEmptyVec::new(x, vec![x * 10])),
+            NonEmptyVec::new(1, vec![10, 2, 20])
+        );
+    }
+
+    #[test]
+    fn reduce_semigroup_only_values() {
+        let values = NonEmptyVec::new(NonEmptyVec::one(1), vec![NonEmptyVec::new(2, vec![3])]);
+        assert_eq!(values.reduce(), NonEmptyVec::new(1, vec![2, 3]));
+    }
+
+    #[test]
+    fn reduce_right_to_starts_from_last() {
+        let values = NonEmptyVec::new("a", vec!["b", "c"]);
+        let joined = values.reduce_right_to(|a| a.to_string(), |acc, a| acc + a);
+        assert_eq!(joined, "cba");
+    }
+
+    #[test]
+    fn sequence_options() {
+        let values = NonEmptyVec::new(Some(1), vec![Some(2)]);
+        assert_eq!(values.sequence(), Some(NonEmptyVec::new(1, vec![2])));
+        let values = NonEmptyVec::new(Some(1), vec![None]);
+        assert_eq!(values.sequence(), None);
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use super::*;
+
+    type NonEmptyVecUsize = NonEmptyVec<usize>;
+
+    fn spread_by_remainder(a: usize, b: usize) -> NonEmptyVecUsize {
+        NonEmptyVec::new(b, (0..a % 3).collect())
+    }
+
+    functor_identity!(NonEmptyVecUsize);
+    functor_composition!(NonEmptyVecUsize);
+    apply_composition!(NonEmptyVecUsize);
+    apply_consistent_with_fmap!(NonEmptyVecUsize);
+    // No apply_consistent_with_flat_map: like Vec, apply runs every function
+    // on one element before moving to the next, so it orders its results by
+    // element rather than by function.
+    applicative_identity!(NonEmptyVecUsize);
+    applicative_homomorphism!(NonEmptyVecUsize);
+    flat_map_associativity!(NonEmptyVecUsize by spread_by_remainder);
+    monad_left_identity!(NonEmptyVecUsize by spread_by_remainder);
+    monad_right_identity!(NonEmptyVecUsize);
+    semigroup_k_associativity!(NonEmptyVecUsize);
+    reduce_left_to_consistent_with_fold_left!(NonEmptyVecUsize);
+    reduce_right_to_consistent_with_fold_right!(NonEmptyVecUsize);
+    reduce_map_consistent_with_fold_map!(NonEmptyVecUsize);
+
+    #[quickcheck]
+    fn apply_consistent_with_vec(fa: NonEmptyVecUsize) -> bool {
+        let functions: [fn(usize) -> usize; 2] = [|a| a / 2, |a| a % 7];
+        let f = NonEmptyVec::new(functions[0], vec![functions[1]]);
+        fa.clone().apply(f).into_vec() == fa.into_vec().apply(functions.to_vec())
+    }
+
+    #[quickcheck]
+    fn reduce_consistent_with_fold(fa: NonEmptyVec<String>) -> bool {
+        let expected = fa.iter().cloned().collect::<String>();
+        fa.reduce() == expected
+    }
+
+    #[quickcheck]
+    fn minimum_and_maximum_consistent_with_iter(fa: NonEmptyVecUsize) -> bool {
+        let min = *fa.iter().min().unwrap();
+        let max = *fa.iter().max().unwrap();
+        fa.clone().minimum() == min && fa.maximum() == max
+    }
+}
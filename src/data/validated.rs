@@ -0,0 +1,269 @@
+use crate::core::prelude::*;
+use crate::data::non_empty_vec::NonEmptyVec;
+use crate::kernel::prelude::Semigroup;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Validated<E, A> {
+    Valid(A),
+    Invalid(E),
+}
+
+pub use Validated::{Invalid, Valid};
+
+pub type ValidatedNel<E, A> = Validated<NonEmptyVec<E>, A>;
+
+impl<E, A> Validated<E, A> {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Valid(_))
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        !self.is_valid()
+    }
+
+    pub fn to_result(self) -> Result<A, E> {
+        self.into()
+    }
+
+    pub fn to_option(self) -> Option<A> {
+        match self {
+            Valid(a) => Some(a),
+            Invalid(_) => None,
+        }
+    }
+
+    pub fn to_validated_nel(self) -> ValidatedNel<E, A> {
+        match self {
+            Valid(a) => Valid(a),
+            Invalid(e) => Invalid(NonEmptyVec::one(e)),
+        }
+    }
+
+    pub fn map_err<F, G>(self, f: G) -> Validated<F, A>
+    where
+        G: FnOnce(E) -> F,
+    {
+        match self {
+            Valid(a) => Valid(a),
+            Invalid(e) => Invalid(f(e)),
+        }
+    }
+}
+
+impl<E, A> ValidatedNel<E, A> {
+    pub fn invalid_nel(error: E) -> Self {
+        Invalid(NonEmptyVec::one(error))
+    }
+}
+
+impl<E, A> From<Result<A, E>> for Validated<E, A> {
+    fn from(result: Result<A, E>) -> Self {
+        match result {
+            Ok(a) => Valid(a),
+            Err(e) => Invalid(e),
+        }
+    }
+}
+
+impl<E, A> From<Validated<E, A>> for Result<A, E> {
+    fn from(validated: Validated<E, A>) -> Self {
+        match validated {
+            Valid(a) => Ok(a),
+            Invalid(e) => Err(e),
+        }
+    }
+}
+
+impl<E, A> Functor for Validated<E, A> {
+    type Inner = A;
+    type Outter<B> = Validated<E, B>;
+
+    fn fmap<F, B>(self, mut f: F) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        match self {
+            Valid(a) => Valid(f(a)),
+            Invalid(e) => Invalid(e),
+        }
+    }
+}
+
+impl<E: Semigroup, A> Apply for Validated<E, A> {
+    fn apply<B, F>(self, f: Self::Outter<F>) -> Self::Outter<B>
+    where
+        F: FnMut(Self::Inner) -> B,
+    {
+        match (self, f) {
+            (Valid(a), Valid(mut f)) => Valid(f(a)),
+            (Invalid(e), Invalid(f_e)) => Invalid(f_e.combine(e)),
+            (Invalid(e), _) | (_, Invalid(e)) => Invalid(e),
+        }
+    }
+}
+
+impl<E: Semigroup, A> Applicative for Validated<E, A> {
+    fn pure(value: Self::Inner) -> Self::Outter<Self::Inner> {
+        Valid(value)
+    }
+}
+
+impl<E: Semigroup, A> ApplicativeError for Validated<E, A> {
+    type ErrorT = E;
+
+    fn handle_error_with<F>(self, mut f: F) -> Self::Outter<Self::Inner>
+    where
+        F: FnMut(Self::ErrorT) -> Self::Outter<Self::Inner>,
+    {
+        match self {
+            Invalid(e) => f(e),
+            _ => self,
+        }
+    }
+
+    fn raise_error(error: Self::ErrorT) -> Self::Outter<Self::Inner> {
+        Invalid(error)
+    }
+}
+
+impl<E: Semigroup, A, T> TraverseApplicative<T> for Validated<E, A> {
+    fn pure_acc(value: T) -> Self::Outter<T> {
+        Valid(value)
+    }
+
+    fn accumulate<F>(acc: Self::Outter<T>, elem: Self, mut f: F) -> Self::Outter<T>
+    where
+        F: FnMut(T, Self::Inner) -> T,
+    {
+        match (acc, elem) {
+            (Valid(t), Valid(a)) => Valid(f(t, a)),
+            (Invalid(acc_e), Invalid(e)) => Invalid(acc_e.combine(e)),
+            (Invalid(e), _) | (_, Invalid(e)) => Invalid(e),
+        }
+    }
+}
+
+impl<E, A> Bifunctor for Validated<E, A> {
+    type Inner1 = E;
+
+    type Inner2 = A;
+
+    type Outter<B, C> = Validated<B, C>;
+
+    fn bimap<F1, F2, B, C>(self, mut f1: F1, mut f2: F2) -> Self::Outter<B, C>
+    where
+        F1: FnMut(Self::Inner1) -> B,
+        F2: FnMut(Self::Inner2) -> C,
+    {
+        match self {
+            Valid(a) => Valid(f2(a)),
+            Invalid(e) => Invalid(f1(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+impl<E: quickcheck::Arbitrary, A: quickcheck::Arbitrary> quickcheck::Arbitrary for Validated<E, A> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Result::<A, E>::arbitrary(g).into()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            Result::<A, E>::from(self.clone())
+                .shrink()
+                .map(Validated::from),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn positive(n: i64) -> ValidatedNel<String, i64> {
+        if n > 0 {
+            Valid(n)
+        } else {
+            Validated::invalid_nel(format!("{} is not positive", n))
+        }
+    }
+
+    #[test]
+    fn apply_accumulates_errors() {
+        let sum = positive(-1).apply(positive(-2).fmap(|a| move |b| a + b));
+        assert_eq!(
+            sum,
+            Invalid(NonEmptyVec::new(
+                "-2 is not positive".to_string(),
+                vec!["-1 is not positive".to_string()]
+            ))
+        );
+    }
+
+    #[test]
+    fn apply_valid_values() {
+        let sum = positive(1).apply(positive(2).fmap(|a| move |b| a + b));
+        assert_eq!(sum, Valid(3));
+    }
+
+    #[test]
+    fn sequence_vec_accumulates_every_error() {
+        let values = vec![positive(1), positive(0), positive(3), positive(-4)];
+        assert_eq!(
+            values.sequence(),
+            Invalid(NonEmptyVec::new(
+                "0 is not positive".to_string(),
+                vec!["-4 is not positive".to_string()]
+            ))
+        );
+    }
+
+    #[test]
+    fn traverse_vec_collects_valid_values() {
+        assert_eq!(vec![1, 2, 3].traverse(positive), Valid(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn result_round_trip() {
+        let ok: Result<u64, String> = Ok(3);
+        let err: Result<u64, String> = Err("oops".to_string());
+        assert_eq!(Validated::from(ok.clone()).to_result(), ok);
+        assert_eq!(Validated::from(err.clone()).to_result(), err);
+    }
+
+    #[test]
+    fn handle_error_with_recovers() {
+        let invalid: Validated<String, u64> = Validated::raise_error("oops".to_string());
+        assert_eq!(
+            invalid.handle_error_with(|e| Valid(e.len() as u64)),
+            Valid(4)
+        );
+    }
+
+    #[test]
+    fn bimap_maps_both_sides() {
+        let invalid: Validated<String, u64> = Invalid("oops".to_string());
+        let valid: Validated<String, u64> = Valid(2);
+        assert_eq!(invalid.bimap(|e| e.len(), |a| a * 2), Invalid(4));
+        assert_eq!(valid.bimap(|e| e.len(), |a| a * 2), Valid(4));
+    }
+
+    #[test]
+    fn to_validated_nel_wraps_the_error() {
+        let invalid: Validated<&str, u64> = Invalid("oops");
+        assert_eq!(invalid.to_validated_nel(), Validated::invalid_nel("oops"));
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use super::*;
+
+    type ValidatedUsize = Validated<String, usize>;
+
+    functor_identity!(ValidatedUsize);
+    apply_composition!(ValidatedUsize);
+    apply_consistent_with_fmap!(ValidatedUsize);
+    applicative_identity!(ValidatedUsize);
+}
@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+use super::semigroup::Semigroup;
+use super::wrappers::{All, Any, First, Last, Max, Min};
+
+/// A semigroup whose `combine` is idempotent: `a.combine(a) == a`.
+pub trait Band: Semigroup {}
+
+impl Band for () {}
+
+impl<A: Eq + Hash, S: BuildHasher> Band for HashSet<A, S> {}
+
+impl<A: Band> Band for Option<A> {}
+
+impl Band for All {}
+impl Band for Any {}
+impl<A> Band for Min<A> where Self: Semigroup {}
+impl<A> Band for Max<A> where Self: Semigroup {}
+impl<A> Band for First<A> {}
+impl<A> Band for Last<A> {}
+
+#[cfg(test)]
+mod laws {
+    macro_rules! idempotence {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                idempotence!([<idempotence_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t) -> bool {
+                use crate::kernel::prelude::*;
+                let n1_copy = n1.clone();
+
+                n1.clone().combine(n1) == n1_copy
+            }
+        };
+    }
+
+    mod idempotence {
+        use crate::kernel::wrappers::*;
+        use std::collections::HashSet;
+
+        type Unit = ();
+        type SetUsize = HashSet<usize>;
+        type OptionSetUsize = Option<HashSet<usize>>;
+        type OptionMinU8 = Option<Min<u8>>;
+        type MinI64 = Min<i64>;
+        type MaxString = Max<String>;
+        type FirstString = First<String>;
+        type LastUsize = Last<usize>;
+        idempotence!(
+            Unit,
+            SetUsize,
+            OptionSetUsize,
+            OptionMinU8,
+            MinI64,
+            MaxString,
+            FirstString,
+            LastUsize,
+            All,
+            Any
+        );
+    }
+}
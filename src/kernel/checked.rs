@@ -0,0 +1,192 @@
+use super::monoid::Monoid;
+use super::semigroup::Semigroup;
+use super::wrappers::{Product, Sum};
+
+/// Arithmetic that yields `Checked(None)` once any combine overflows; `None`
+/// then absorbs everything it is combined with.
+///
+/// Only `Checked<Sum<_>>` over unsigned integers is associative, so only it
+/// is a `Semigroup`. Signed sums can overflow on one grouping and not the
+/// other, e.g. `(MAX + 1) + -1` against `MAX + (1 + -1)`, and products can
+/// overflow before a zero would have absorbed the overflow; those are
+/// available as the plain `checked_add` and `checked_mul` instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checked<A>(pub Option<A>);
+
+impl<A> Checked<A> {
+    pub fn new(a: A) -> Self {
+        Checked(Some(a))
+    }
+
+    pub fn into_option(self) -> Option<A> {
+        self.0
+    }
+
+    pub fn is_overflow(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<A> From<A> for Checked<A> {
+    fn from(a: A) -> Self {
+        Checked::new(a)
+    }
+}
+
+macro_rules! impl_checked_sum {
+    ($($t:ty),*) => {$(
+        impl Semigroup for Checked<Sum<$t>> {
+            fn combine(self, b: Self) -> Self {
+                match (self.0, b.0) {
+                    (Some(Sum(a)), Some(Sum(b))) => Checked(a.checked_add(b).map(Sum)),
+                    _ => Checked(None),
+                }
+            }
+        }
+
+        impl Monoid for Checked<Sum<$t>> {
+            fn empty() -> Self {
+                Checked::new(Sum(0))
+            }
+        }
+    )*};
+}
+
+impl_checked_sum!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_checked_add {
+    ($($t:ty),*) => {$(
+        impl Checked<Sum<$t>> {
+            /// Not associative, see [`Checked`].
+            pub fn checked_add(self, b: Self) -> Self {
+                match (self.0, b.0) {
+                    (Some(Sum(a)), Some(Sum(b))) => Checked(a.checked_add(b).map(Sum)),
+                    _ => Checked(None),
+                }
+            }
+        }
+    )*};
+}
+
+impl_checked_add!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_checked_mul {
+    ($($t:ty),*) => {$(
+        impl Checked<Product<$t>> {
+            /// Not associative, see [`Checked`].
+            pub fn checked_mul(self, b: Self) -> Self {
+                match (self.0, b.0) {
+                    (Some(Product(a)), Some(Product(b))) => {
+                        Checked(a.checked_mul(b).map(Product))
+                    }
+                    _ => Checked(None),
+                }
+            }
+        }
+    )*};
+}
+
+impl_checked_mul!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+pub(crate) mod boundary {
+    // Picks values next to MIN, MAX and zero much more often than uniform
+    // generation would, so the overflow paths are actually exercised.
+    macro_rules! near_bounds {
+        ($($name:ident: $t:ty),*) => {$(
+            pub(crate) fn $name(g: &mut quickcheck::Gen) -> $t {
+                use quickcheck::Arbitrary;
+                let offset = (u8::arbitrary(g) % 4) as $t;
+                match u8::arbitrary(g) % 4 {
+                    0 => <$t>::MIN + offset,
+                    1 => <$t>::MAX - offset,
+                    2 => offset,
+                    _ => <$t>::arbitrary(g),
+                }
+            }
+        )*};
+    }
+
+    near_bounds!(
+        near_bounds_u8: u8,
+        near_bounds_u16: u16,
+        near_bounds_u32: u32,
+        near_bounds_u64: u64,
+        near_bounds_u128: u128,
+        near_bounds_usize: usize
+    );
+}
+
+#[cfg(test)]
+macro_rules! impl_arbitrary_checked {
+    ($($t:ty: $gen:ident),*) => {$(
+        impl quickcheck::Arbitrary for Checked<Sum<$t>> {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                if <u8 as quickcheck::Arbitrary>::arbitrary(g) < 32 {
+                    Checked(None)
+                } else {
+                    Checked::new(Sum(boundary::$gen(g)))
+                }
+            }
+        }
+    )*};
+}
+
+#[cfg(test)]
+impl_arbitrary_checked!(
+    u8: near_bounds_u8,
+    u16: near_bounds_u16,
+    u32: near_bounds_u32,
+    u64: near_bounds_u64,
+    u128: near_bounds_u128,
+    usize: near_bounds_usize
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::prelude::*;
+
+    #[test]
+    fn overflow_yields_none() {
+        assert_eq!(
+            Checked::new(Sum(u8::MAX)).combine(Checked::new(Sum(1))),
+            Checked(None)
+        );
+        assert_eq!(
+            Checked::new(Sum(i32::MIN)).checked_add(Checked::new(Sum(-1))),
+            Checked(None)
+        );
+        assert_eq!(
+            Checked::new(Product(i64::MAX / 2)).checked_mul(Checked::new(Product(3))),
+            Checked(None)
+        );
+    }
+
+    #[test]
+    fn overflow_is_sticky() {
+        let counts = vec![u8::MAX, 1, 0];
+        let total = counts.fold_map(|n| Checked::new(Sum(n)));
+        assert!(total.is_overflow());
+    }
+
+    #[test]
+    fn without_overflow_it_agrees_with_plain_arithmetic() {
+        let counts = vec![100u32, 200, 300];
+        assert_eq!(
+            counts.fold_map(|n| Checked::new(Sum(n))).into_option(),
+            Some(Sum(600))
+        );
+    }
+
+    #[test]
+    fn signed_sums_are_not_associative() {
+        let (a, b, c) = (
+            Checked::new(Sum(i8::MAX)),
+            Checked::new(Sum(1)),
+            Checked::new(Sum(-1)),
+        );
+        assert_eq!(a.checked_add(b).checked_add(c), Checked(None));
+        assert_eq!(a.checked_add(b.checked_add(c)), Checked::new(Sum(i8::MAX)));
+    }
+}
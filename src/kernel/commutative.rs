@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+use super::monoid::Monoid;
+use super::semigroup::Semigroup;
+use super::wrappers::{All, Any, Max, Min, Product, Sum};
+
+/// A semigroup whose `combine` does not depend on the order of its arguments.
+pub trait CommutativeSemigroup: Semigroup {}
+
+pub trait CommutativeMonoid: Monoid + CommutativeSemigroup {}
+
+macro_rules! impl_commutative {
+    ($($t:ty),*) => {$(
+        impl CommutativeSemigroup for $t {}
+        impl CommutativeMonoid for $t {}
+        impl CommutativeSemigroup for Sum<$t> {}
+        impl CommutativeMonoid for Sum<$t> {}
+        impl CommutativeSemigroup for Product<$t> {}
+        impl CommutativeMonoid for Product<$t> {}
+    )*};
+}
+
+impl_commutative!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl CommutativeSemigroup for () {}
+impl CommutativeMonoid for () {}
+
+impl<A: Eq + Hash, S: BuildHasher> CommutativeSemigroup for HashSet<A, S> {}
+impl<A: Eq + Hash, S: BuildHasher + Default> CommutativeMonoid for HashSet<A, S> {}
+
+impl<A: CommutativeSemigroup> CommutativeSemigroup for Option<A> {}
+impl<A: CommutativeSemigroup> CommutativeMonoid for Option<A> {}
+
+impl CommutativeSemigroup for All {}
+impl CommutativeMonoid for All {}
+impl CommutativeSemigroup for Any {}
+impl CommutativeMonoid for Any {}
+
+impl<A> CommutativeSemigroup for Min<A> where Self: Semigroup {}
+impl<A> CommutativeMonoid for Min<A> where Self: Monoid {}
+impl<A> CommutativeSemigroup for Max<A> where Self: Semigroup {}
+impl<A> CommutativeMonoid for Max<A> where Self: Monoid {}
+
+#[cfg(test)]
+mod laws {
+    macro_rules! commutativity {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                commutativity!([<commutativity_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t, n2: $t) -> bool {
+                use crate::kernel::prelude::*;
+                let n1_copy = n1.clone();
+                let n2_copy = n2.clone();
+
+                n1.combine(n2) == n2_copy.combine(n1_copy)
+            }
+        };
+    }
+
+    mod commutativity {
+        use crate::kernel::merge_map::MergeMap;
+        use crate::kernel::wrappers::*;
+        use std::collections::{BTreeMap, HashMap, HashSet};
+
+        type Unit = ();
+        type SetUsize = HashSet<usize>;
+        type OptionU8 = Option<u8>;
+        type OptionSetUsize = Option<HashSet<usize>>;
+        type SumU16 = Sum<u16>;
+        type ProductI32 = Product<i32>;
+        type MinI64 = Min<i64>;
+        type MaxString = Max<String>;
+        type MergeHashMapUsizeU32 = MergeMap<HashMap<usize, u32>>;
+        type MergeBTreeMapU8SetU8 = MergeMap<BTreeMap<u8, HashSet<u8>>>;
+        commutativity!(
+            i8,
+            i32,
+            u64,
+            usize,
+            Unit,
+            SetUsize,
+            OptionU8,
+            OptionSetUsize,
+            SumU16,
+            ProductI32,
+            MinI64,
+            MaxString,
+            All,
+            Any,
+            MergeHashMapUsizeU32,
+            MergeBTreeMapU8SetU8
+        );
+    }
+}
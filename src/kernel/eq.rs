@@ -0,0 +1,268 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::BuildHasher;
+
+pub trait Eq {
+    fn eqv(&self, other: &Self) -> bool;
+
+    fn neqv(&self, other: &Self) -> bool {
+        !self.eqv(other)
+    }
+}
+
+macro_rules! impl_eq_from_std {
+    ($($t:ty),*) => {$(
+        impl Eq for $t {
+            fn eqv(&self, other: &Self) -> bool {
+                self == other
+            }
+        }
+    )*};
+}
+
+impl_eq_from_std!(
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    str,
+    String,
+    ()
+);
+
+// Floats are compared by their total order, so NaN equals itself and the
+// instance stays reflexive.
+impl Eq for f32 {
+    fn eqv(&self, other: &Self) -> bool {
+        self.total_cmp(other).is_eq()
+    }
+}
+
+impl Eq for f64 {
+    fn eqv(&self, other: &Self) -> bool {
+        self.total_cmp(other).is_eq()
+    }
+}
+
+impl<A: Eq + ?Sized> Eq for &A {
+    fn eqv(&self, other: &Self) -> bool {
+        (**self).eqv(*other)
+    }
+}
+
+impl<A: Eq + ?Sized> Eq for Box<A> {
+    fn eqv(&self, other: &Self) -> bool {
+        (**self).eqv(&**other)
+    }
+}
+
+impl<A: Eq> Eq for Option<A> {
+    fn eqv(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.eqv(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<A: Eq, E: Eq> Eq for Result<A, E> {
+    fn eqv(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Ok(a), Ok(b)) => a.eqv(b),
+            (Err(a), Err(b)) => a.eqv(b),
+            _ => false,
+        }
+    }
+}
+
+pub(crate) fn eqv_iter<'a, A: Eq + 'a>(
+    a: impl IntoIterator<Item = &'a A>,
+    b: impl IntoIterator<Item = &'a A>,
+) -> bool {
+    let mut b = b.into_iter();
+    for a in a {
+        match b.next() {
+            Some(b) if a.eqv(b) => {}
+            _ => return false,
+        }
+    }
+    b.next().is_none()
+}
+
+impl<A: Eq> Eq for [A] {
+    fn eqv(&self, other: &Self) -> bool {
+        eqv_iter(self, other)
+    }
+}
+
+impl<A: Eq, const N: usize> Eq for [A; N] {
+    fn eqv(&self, other: &Self) -> bool {
+        eqv_iter(self, other)
+    }
+}
+
+impl<A: Eq> Eq for Vec<A> {
+    fn eqv(&self, other: &Self) -> bool {
+        eqv_iter(self, other)
+    }
+}
+
+impl<A: Eq> Eq for VecDeque<A> {
+    fn eqv(&self, other: &Self) -> bool {
+        eqv_iter(self, other)
+    }
+}
+
+impl<A: Eq> Eq for LinkedList<A> {
+    fn eqv(&self, other: &Self) -> bool {
+        eqv_iter(self, other)
+    }
+}
+
+impl<A: Eq> Eq for BTreeSet<A> {
+    fn eqv(&self, other: &Self) -> bool {
+        eqv_iter(self, other)
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for BTreeMap<K, V> {
+    fn eqv(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|((k1, v1), (k2, v2))| k1.eqv(k2) && v1.eqv(v2))
+    }
+}
+
+impl<A: std::cmp::Eq + std::hash::Hash, S: BuildHasher> Eq for HashSet<A, S> {
+    fn eqv(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<K: std::cmp::Eq + std::hash::Hash, V: Eq, S: BuildHasher> Eq for HashMap<K, V, S> {
+    fn eqv(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(k, v)| matches!(other.get(k), Some(other) if v.eqv(other)))
+    }
+}
+
+macro_rules! impl_eq_tuple {
+    ($(($($t:ident $i:tt),+)),*) => {$(
+        impl<$($t: Eq),+> Eq for ($($t,)+) {
+            fn eqv(&self, other: &Self) -> bool {
+                $(self.$i.eqv(&other.$i))&&+
+            }
+        }
+    )*};
+}
+
+impl_eq_tuple!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+);
+
+#[cfg(test)]
+mod laws {
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+    macro_rules! eq_laws {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                eq_laws!([<reflexivity_ $t>], [<symmetry_ $t>], [<transitivity_ $t>]: $t);
+            }
+        )*};
+        ($reflexivity:ident, $symmetry:ident, $transitivity:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $reflexivity(n1: $t) -> bool {
+                use crate::kernel::eq::Eq;
+
+                n1.eqv(&n1)
+            }
+
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $symmetry(n1: $t, n2: $t) -> bool {
+                use crate::kernel::eq::Eq;
+
+                n1.eqv(&n2) == n2.eqv(&n1)
+            }
+
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $transitivity(n1: $t, n2: $t, n3: $t) -> bool {
+                use crate::kernel::eq::Eq;
+
+                !(n1.eqv(&n2) && n2.eqv(&n3)) || n1.eqv(&n3)
+            }
+        };
+    }
+
+    type Unit = ();
+    type OptionU8 = Option<u8>;
+    type ResultU8Bool = Result<u8, bool>;
+    type VecU8 = Vec<u8>;
+    type TupleU8Bool = (u8, bool);
+    type BTreeSetU8 = BTreeSet<u8>;
+    type BTreeMapU8U8 = BTreeMap<u8, u8>;
+    type HashSetU8 = HashSet<u8>;
+    type HashMapU8U8 = HashMap<u8, u8>;
+
+    eq_laws!(
+        bool,
+        u8,
+        i64,
+        f32,
+        f64,
+        String,
+        Unit,
+        OptionU8,
+        ResultU8Bool,
+        VecU8,
+        TupleU8Bool,
+        BTreeSetU8,
+        BTreeMapU8U8,
+        HashSetU8,
+        HashMapU8U8
+    );
+
+    #[quickcheck]
+    fn eqv_consistent_with_std(a: Vec<(u8, Option<String>)>, b: Vec<(u8, Option<String>)>) -> bool {
+        use crate::kernel::eq::Eq;
+
+        a.eqv(&b) == (a == b) && a.eqv(&a.clone())
+    }
+
+    #[test]
+    fn nan_is_equal_to_itself() {
+        use crate::kernel::eq::Eq;
+
+        assert!(f64::NAN.eqv(&f64::NAN));
+        assert!(f32::NAN.eqv(&f32::NAN));
+        assert!(0.0f64.neqv(&-0.0f64));
+    }
+}
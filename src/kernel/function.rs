@@ -0,0 +1,141 @@
+use super::monoid::Monoid;
+use super::semigroup::Semigroup;
+
+/// A function from a type to itself. `f.combine(g)` is the composition
+/// `f ∘ g`: `g` runs first and `f` is applied to its result.
+pub struct Endo<A>(pub Box<dyn Fn(A) -> A>);
+
+impl<A> Endo<A> {
+    pub fn new(f: impl Fn(A) -> A + 'static) -> Self {
+        Endo(Box::new(f))
+    }
+
+    pub fn run(&self, a: A) -> A {
+        (self.0)(a)
+    }
+}
+
+impl<A: 'static> Semigroup for Endo<A> {
+    fn combine(self, b: Self) -> Self {
+        let (f, g) = (self.0, b.0);
+        Endo(Box::new(move |a| f(g(a))))
+    }
+}
+
+impl<A: 'static> Monoid for Endo<A> {
+    fn empty() -> Self {
+        Endo(Box::new(|a| a))
+    }
+}
+
+/// A function whose results are combined pointwise through `B: Semigroup`.
+pub struct FnMonoid<A, B>(pub Box<dyn Fn(A) -> B>);
+
+impl<A, B> FnMonoid<A, B> {
+    pub fn new(f: impl Fn(A) -> B + 'static) -> Self {
+        FnMonoid(Box::new(f))
+    }
+
+    pub fn run(&self, a: A) -> B {
+        (self.0)(a)
+    }
+}
+
+impl<A: Clone + 'static, B: Semigroup + 'static> Semigroup for FnMonoid<A, B> {
+    fn combine(self, b: Self) -> Self {
+        let (f, g) = (self.0, b.0);
+        FnMonoid(Box::new(move |a: A| f(a.clone()).combine(g(a))))
+    }
+}
+
+impl<A: Clone + 'static, B: Monoid + 'static> Monoid for FnMonoid<A, B> {
+    fn empty() -> Self {
+        FnMonoid(Box::new(|_| B::empty()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::prelude::*;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Config {
+        retries: u32,
+        verbose: bool,
+        name: String,
+    }
+
+    #[test]
+    fn combine_runs_the_right_function_first() {
+        let add_one = Endo::new(|a: i32| a + 1);
+        let double = Endo::new(|a: i32| a * 2);
+        assert_eq!(add_one.combine(double).run(5), 11);
+    }
+
+    #[test]
+    fn folding_configuration_transformers() {
+        let transformers = vec![
+            Endo::new(|c: Config| Config { retries: 3, ..c }),
+            Endo::new(|c: Config| Config { verbose: true, ..c }),
+            Endo::new(|c: Config| Config {
+                name: "worker".to_string(),
+                ..c
+            }),
+        ];
+        let expected = Config {
+            retries: 3,
+            verbose: true,
+            name: "worker".to_string(),
+        };
+        assert_eq!(transformers.folding().run(Config::default()), expected);
+    }
+
+    #[test]
+    fn fn_monoid_combines_outputs() {
+        let name = FnMonoid::new(|id: u32| format!("user-{}", id));
+        let suffix = FnMonoid::new(|id: u32| if id > 9 { "!" } else { "?" }.to_string());
+        assert_eq!(name.combine(suffix).run(42), "user-42!");
+        assert_eq!(FnMonoid::<u32, String>::empty().run(42), "");
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use super::*;
+    use crate::testing::function;
+
+    fn endo(picked: u8) -> Endo<usize> {
+        Endo::new(function(picked))
+    }
+
+    fn fn_monoid(picked: u8) -> FnMonoid<usize, String> {
+        let f = function(picked);
+        FnMonoid::new(move |a| f(a).to_string())
+    }
+
+    macro_rules! function_monoid_laws {
+        ($($make:ident: $t:ident),*) => {$(
+            paste::paste! {
+                #[quickcheck]
+                fn [<$make _associativity>](f: u8, g: u8, h: u8, a: usize) -> bool {
+                    let left = $make(f).combine($make(g).combine($make(h)));
+                    let right = $make(f).combine($make(g)).combine($make(h));
+                    left.run(a) == right.run(a)
+                }
+
+                #[quickcheck]
+                fn [<$make _left_identity>](f: u8, a: usize) -> bool {
+                    $t::empty().combine($make(f)).run(a) == $make(f).run(a)
+                }
+
+                #[quickcheck]
+                fn [<$make _right_identity>](f: u8, a: usize) -> bool {
+                    $make(f).combine($t::empty()).run(a) == $make(f).run(a)
+                }
+            }
+        )*};
+    }
+
+    function_monoid_laws!(endo: Endo, fn_monoid: FnMonoid);
+}
@@ -0,0 +1,254 @@
+use super::monoid::Monoid;
+use super::semigroup::repeated_squaring;
+
+pub trait Group: Monoid {
+    fn inverse(self) -> Self;
+
+    fn remove(self, b: Self) -> Self
+    where
+        Self: Sized,
+    {
+        self.combine(b.inverse())
+    }
+
+    /// Like [`combine_n_nonzero`](super::semigroup::Semigroup::combine_n_nonzero), but `n`
+    /// may be zero or negative; a negative `n` repeats the inverse.
+    fn combine_n(self, n: i64) -> Self
+    where
+        Self: Sized + Clone,
+    {
+        match n {
+            0 => Self::empty(),
+            n if n < 0 => repeated_squaring(self.inverse(), n.unsigned_abs()),
+            n => repeated_squaring(self, n.unsigned_abs()),
+        }
+    }
+}
+
+macro_rules! impl_group_wrapping {
+    ($($t:ty),*) => {$(
+        impl Group for $t {
+            fn inverse(self) -> Self {
+                self.wrapping_neg()
+            }
+
+            fn remove(self, b: Self) -> Self {
+                self.wrapping_sub(b)
+            }
+        }
+    )*};
+}
+
+impl_group_wrapping!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Group for f32 {
+    fn inverse(self) -> Self {
+        -self
+    }
+
+    fn remove(self, b: Self) -> Self {
+        self - b
+    }
+}
+
+impl Group for f64 {
+    fn inverse(self) -> Self {
+        -self
+    }
+
+    fn remove(self, b: Self) -> Self {
+        self - b
+    }
+}
+
+impl Group for () {
+    fn inverse(self) -> Self {}
+}
+
+macro_rules! impl_group_tuple {
+    ($(($($t:ident $i:tt),+)),*) => {$(
+        impl<$($t: Group),+> Group for ($($t,)+) {
+            fn inverse(self) -> Self {
+                ($(self.$i.inverse(),)+)
+            }
+        }
+    )*};
+}
+
+impl_group_tuple!(
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::semigroup::Semigroup;
+
+    #[test]
+    fn remove_undoes_combine() {
+        let running = 10i32.combine(7).combine(3);
+        assert_eq!(running.remove(7), 13);
+        assert_eq!(3u8.remove(5), 254);
+        assert_eq!((4i64, 2.5f64).remove((1, 0.5)), (3, 2.0));
+    }
+
+    #[test]
+    fn combine_n_handles_negative_and_zero() {
+        assert_eq!(3i32.combine_n(4), 12);
+        assert_eq!(3i32.combine_n(-4), -12);
+        assert_eq!(3i32.combine_n(0), 0);
+        assert_eq!(1u8.combine_n(-1), u8::MAX);
+        assert_eq!(1i64.combine_n(i64::MIN), i64::MIN);
+    }
+
+    #[quickcheck]
+    fn combine_n_agrees_with_repeated_combine(a: i32, n: i8) -> bool {
+        let naive = (0..n.unsigned_abs()).fold(0i32, |acc, _| acc.combine(a));
+        let expected = if n < 0 { naive.inverse() } else { naive };
+        a.combine_n(n as i64) == expected
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    macro_rules! left_inverse {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                left_inverse!([<left_inverse_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: f64) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: f64) -> bool {
+                use crate::kernel::prelude::*;
+                use float_cmp::{approx_eq, F64Margin};
+
+                let left = n1.inverse().combine(n1);
+
+                !n1.is_finite() || approx_eq!(f64, left, <f64 as Monoid>::empty(), F64Margin::default())
+            }
+        };
+        ($name:ident: f32) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: f32) -> bool {
+                use crate::kernel::prelude::*;
+                use float_cmp::{approx_eq, F32Margin};
+
+                let left = n1.inverse().combine(n1);
+
+                !n1.is_finite() || approx_eq!(f32, left, <f32 as Monoid>::empty(), F32Margin::default())
+            }
+        };
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t) -> bool {
+                use crate::kernel::prelude::*;
+                let n1_copy = n1.clone();
+
+                n1.inverse().combine(n1_copy) == <$t as Monoid>::empty()
+            }
+        };
+    }
+
+    macro_rules! right_inverse {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                right_inverse!([<right_inverse_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: f64) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: f64) -> bool {
+                use crate::kernel::prelude::*;
+                use float_cmp::{approx_eq, F64Margin};
+
+                let right = n1.combine(n1.inverse());
+
+                !n1.is_finite() || approx_eq!(f64, right, <f64 as Monoid>::empty(), F64Margin::default())
+            }
+        };
+        ($name:ident: f32) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: f32) -> bool {
+                use crate::kernel::prelude::*;
+                use float_cmp::{approx_eq, F32Margin};
+
+                let right = n1.combine(n1.inverse());
+
+                !n1.is_finite() || approx_eq!(f32, right, <f32 as Monoid>::empty(), F32Margin::default())
+            }
+        };
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t) -> bool {
+                use crate::kernel::prelude::*;
+                let n1_copy = n1.clone();
+
+                n1.combine(n1_copy.inverse()) == <$t as Monoid>::empty()
+            }
+        };
+    }
+
+    type Unit = ();
+    type TupleI32U8 = (i32, u8);
+    type TupleI8I64Usize = (i8, i64, usize);
+    type TupleI16U16I32U32 = (i16, u16, i32, u32);
+
+    mod left_inverse {
+        use super::*;
+        left_inverse!(
+            i8,
+            i16,
+            i32,
+            i64,
+            u8,
+            u16,
+            u32,
+            u64,
+            usize,
+            Unit,
+            TupleI32U8,
+            TupleI8I64Usize,
+            TupleI16U16I32U32,
+            f64,
+            f32
+        );
+    }
+
+    mod right_inverse {
+        use super::*;
+        right_inverse!(
+            i8,
+            i16,
+            i32,
+            i64,
+            u8,
+            u16,
+            u32,
+            u64,
+            usize,
+            Unit,
+            TupleI32U8,
+            TupleI8I64Usize,
+            TupleI16U16I32U32,
+            f64,
+            f32
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasher, Hasher};
+
+use super::eq::Eq;
+
+/// Hashing consistent with [`Eq`]: values that are `eqv` must have the same
+/// `hash_value`.
+pub trait Hash: Eq {
+    fn hash_value(&self) -> u64;
+}
+
+fn hash_std<A: std::hash::Hash + ?Sized>(value: &A) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn hash_values(hashes: impl IntoIterator<Item = u64>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for hash in hashes {
+        hasher.write_u64(hash);
+    }
+    hasher.finish()
+}
+
+macro_rules! impl_hash_from_std {
+    ($($t:ty),*) => {$(
+        impl Hash for $t {
+            fn hash_value(&self) -> u64 {
+                hash_std(self)
+            }
+        }
+    )*};
+}
+
+impl_hash_from_std!(
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    str,
+    String,
+    ()
+);
+
+impl Hash for f32 {
+    fn hash_value(&self) -> u64 {
+        hash_std(&self.to_bits())
+    }
+}
+
+impl Hash for f64 {
+    fn hash_value(&self) -> u64 {
+        hash_std(&self.to_bits())
+    }
+}
+
+impl<A: Hash + ?Sized> Hash for &A {
+    fn hash_value(&self) -> u64 {
+        (**self).hash_value()
+    }
+}
+
+impl<A: Hash + ?Sized> Hash for Box<A> {
+    fn hash_value(&self) -> u64 {
+        (**self).hash_value()
+    }
+}
+
+impl<A: Hash> Hash for Option<A> {
+    fn hash_value(&self) -> u64 {
+        match self {
+            Some(a) => hash_values([1, a.hash_value()]),
+            None => hash_values([0]),
+        }
+    }
+}
+
+impl<A: Hash, E: Hash> Hash for Result<A, E> {
+    fn hash_value(&self) -> u64 {
+        match self {
+            Ok(a) => hash_values([0, a.hash_value()]),
+            Err(e) => hash_values([1, e.hash_value()]),
+        }
+    }
+}
+
+impl<A: Hash> Hash for [A] {
+    fn hash_value(&self) -> u64 {
+        hash_values(self.iter().map(Hash::hash_value))
+    }
+}
+
+impl<A: Hash, const N: usize> Hash for [A; N] {
+    fn hash_value(&self) -> u64 {
+        self[..].hash_value()
+    }
+}
+
+impl<A: Hash> Hash for Vec<A> {
+    fn hash_value(&self) -> u64 {
+        self[..].hash_value()
+    }
+}
+
+impl<A: Hash> Hash for VecDeque<A> {
+    fn hash_value(&self) -> u64 {
+        hash_values(self.iter().map(Hash::hash_value))
+    }
+}
+
+impl<A: Hash> Hash for LinkedList<A> {
+    fn hash_value(&self) -> u64 {
+        hash_values(self.iter().map(Hash::hash_value))
+    }
+}
+
+impl<A: Hash> Hash for BTreeSet<A> {
+    fn hash_value(&self) -> u64 {
+        hash_values(self.iter().map(Hash::hash_value))
+    }
+}
+
+impl<K: Hash, V: Hash> Hash for BTreeMap<K, V> {
+    fn hash_value(&self) -> u64 {
+        hash_values(self.iter().map(|entry| entry.hash_value()))
+    }
+}
+
+// Iteration order of the hash collections is unspecified, so their element
+// hashes are combined with a commutative operation.
+impl<A: Hash + std::cmp::Eq + std::hash::Hash, S: BuildHasher> Hash for HashSet<A, S> {
+    fn hash_value(&self) -> u64 {
+        self.iter().fold(hash_std(&self.len()), |acc, a| {
+            acc.wrapping_add(a.hash_value())
+        })
+    }
+}
+
+impl<K, V, S> Hash for HashMap<K, V, S>
+where
+    K: Hash + std::cmp::Eq + std::hash::Hash,
+    V: Hash,
+    S: BuildHasher,
+{
+    fn hash_value(&self) -> u64 {
+        self.iter().fold(hash_std(&self.len()), |acc, entry| {
+            acc.wrapping_add(entry.hash_value())
+        })
+    }
+}
+
+macro_rules! impl_hash_tuple {
+    ($(($($t:ident $i:tt),+)),*) => {$(
+        impl<$($t: Hash),+> Hash for ($($t,)+) {
+            fn hash_value(&self) -> u64 {
+                hash_values([$(self.$i.hash_value()),+])
+            }
+        }
+    )*};
+}
+
+impl_hash_tuple!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+);
+
+#[cfg(test)]
+mod laws {
+    use std::collections::{BTreeSet, HashMap, HashSet};
+
+    macro_rules! hash_consistent_with_eqv {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                hash_consistent_with_eqv!([<hash_consistent_with_eqv_ $t>]: $t);
+            }
+        )*};
+        ($name:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t, n2: $t) -> bool {
+                use crate::kernel::eq::Eq;
+                use crate::kernel::hash::Hash;
+                let n1_copy = n1.clone();
+
+                n1.hash_value() == n1_copy.hash_value()
+                    && (!n1.eqv(&n2) || n1.hash_value() == n2.hash_value())
+            }
+        };
+    }
+
+    type OptionU8 = Option<u8>;
+    type VecU8 = Vec<u8>;
+    type TupleU8Bool = (u8, bool);
+    type BTreeSetU8 = BTreeSet<u8>;
+    type HashSetU8 = HashSet<u8>;
+    type HashMapU8U8 = HashMap<u8, u8>;
+
+    hash_consistent_with_eqv!(
+        bool,
+        u8,
+        f64,
+        String,
+        OptionU8,
+        VecU8,
+        TupleU8Bool,
+        BTreeSetU8,
+        HashSetU8,
+        HashMapU8U8
+    );
+
+    #[quickcheck]
+    fn hash_set_hash_ignores_insertion_order(values: Vec<u8>) -> bool {
+        use crate::kernel::hash::Hash;
+
+        let forward: HashSet<u8> = values.iter().cloned().collect();
+        let backward: HashSet<u8> = values.iter().rev().cloned().collect();
+        forward.hash_value() == backward.hash_value()
+    }
+}
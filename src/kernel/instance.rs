@@ -0,0 +1,132 @@
+use super::monoid::Monoid;
+use super::semigroup::Semigroup;
+
+/// A `Semigroup` as a value, for types with more than one lawful combine.
+/// The trait impl can still be used through `SemigroupInstance::of`.
+pub struct SemigroupInstance<A> {
+    combine: Box<dyn Fn(A, A) -> A>,
+}
+
+impl<A> SemigroupInstance<A> {
+    /// `combine` must be associative.
+    pub fn new(combine: impl Fn(A, A) -> A + 'static) -> Self {
+        SemigroupInstance {
+            combine: Box::new(combine),
+        }
+    }
+
+    pub fn of() -> Self
+    where
+        A: Semigroup + 'static,
+    {
+        SemigroupInstance::new(A::combine)
+    }
+
+    /// The instance of the wrapper `W`, e.g. `SemigroupInstance::via(Max, |m| m.0)`.
+    pub fn via<W: Semigroup + 'static>(wrap: fn(A) -> W, unwrap: fn(W) -> A) -> Self
+    where
+        A: 'static,
+    {
+        SemigroupInstance::new(move |a, b| unwrap(wrap(a).combine(wrap(b))))
+    }
+
+    pub fn combine(&self, a: A, b: A) -> A {
+        (self.combine)(a, b)
+    }
+
+    pub fn combine_all_option(&self, iter: impl IntoIterator<Item = A>) -> Option<A> {
+        iter.into_iter().reduce(|acc, a| self.combine(acc, a))
+    }
+}
+
+/// A `Monoid` as a value. See `SemigroupInstance`.
+pub struct MonoidInstance<A> {
+    semigroup: SemigroupInstance<A>,
+    empty: Box<dyn Fn() -> A>,
+}
+
+impl<A> MonoidInstance<A> {
+    /// `empty` must be an identity for `combine`.
+    pub fn new(empty: impl Fn() -> A + 'static, combine: impl Fn(A, A) -> A + 'static) -> Self {
+        MonoidInstance {
+            semigroup: SemigroupInstance::new(combine),
+            empty: Box::new(empty),
+        }
+    }
+
+    pub fn of() -> Self
+    where
+        A: Monoid + 'static,
+    {
+        MonoidInstance::new(A::empty, A::combine)
+    }
+
+    /// The instance of the wrapper `W`, e.g. `MonoidInstance::via(Product, |p| p.0)`.
+    pub fn via<W: Monoid + 'static>(wrap: fn(A) -> W, unwrap: fn(W) -> A) -> Self
+    where
+        A: 'static,
+    {
+        MonoidInstance {
+            semigroup: SemigroupInstance::via(wrap, unwrap),
+            empty: Box::new(move || unwrap(W::empty())),
+        }
+    }
+
+    pub fn empty(&self) -> A {
+        (self.empty)()
+    }
+
+    pub fn combine(&self, a: A, b: A) -> A {
+        self.semigroup.combine(a, b)
+    }
+
+    pub fn combine_all(&self, iter: impl IntoIterator<Item = A>) -> A {
+        iter.into_iter()
+            .fold(self.empty(), |acc, a| self.combine(acc, a))
+    }
+
+    pub fn semigroup(&self) -> &SemigroupInstance<A> {
+        &self.semigroup
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::wrappers::{Max, Product};
+
+    #[quickcheck]
+    fn of_agrees_with_the_trait(x: Vec<String>) -> bool {
+        MonoidInstance::of().combine_all(x.clone()) == String::combine_all(x)
+    }
+
+    #[quickcheck]
+    fn via_uses_the_wrapper_instance(x: Vec<u64>) -> bool {
+        let max = MonoidInstance::via(Max, |m: Max<u64>| m.0);
+        max.combine_all(x.clone()) == x.into_iter().max().unwrap_or(0)
+    }
+
+    #[test]
+    fn several_instances_for_one_type() {
+        let sum = MonoidInstance::<u64>::of();
+        let product = MonoidInstance::via(Product, |p: Product<u64>| p.0);
+        let values = vec![2, 3, 4];
+        assert_eq!(sum.combine_all(values.clone()), 9);
+        assert_eq!(product.combine_all(values), 24);
+        assert_eq!(product.empty(), 1);
+    }
+
+    #[test]
+    fn from_closures() {
+        let gcd = SemigroupInstance::new(|mut a: u32, mut b: u32| {
+            while b != 0 {
+                let r = a % b;
+                a = b;
+                b = r;
+            }
+            a
+        });
+        assert_eq!(gcd.combine_all_option(vec![12, 18, 30]), Some(6));
+        assert_eq!(gcd.combine_all_option(Vec::new()), None);
+    }
+}
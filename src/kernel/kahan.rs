@@ -0,0 +1,97 @@
+use super::monoid::Monoid;
+use super::semigroup::Semigroup;
+
+/// A float sum that carries a running compensation term (Neumaier's variant
+/// of Kahan summation), so the rounding error of each addition is recovered
+/// instead of accumulating. Combining is associative up to a few ulps.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct KahanSum<F> {
+    sum: F,
+    compensation: F,
+}
+
+macro_rules! impl_kahan_sum {
+    ($($t:ty),*) => {$(
+        impl KahanSum<$t> {
+            pub fn new(value: $t) -> Self {
+                KahanSum {
+                    sum: value,
+                    compensation: 0.0,
+                }
+            }
+
+            pub fn plus(self, value: $t) -> Self {
+                let sum = self.sum + value;
+                let error = if self.sum.abs() >= value.abs() {
+                    (self.sum - sum) + value
+                } else {
+                    (value - sum) + self.sum
+                };
+                KahanSum {
+                    sum,
+                    compensation: self.compensation + error,
+                }
+            }
+
+            pub fn total(&self) -> $t {
+                self.sum + self.compensation
+            }
+        }
+
+        impl From<$t> for KahanSum<$t> {
+            fn from(value: $t) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl Semigroup for KahanSum<$t> {
+            fn combine(self, b: Self) -> Self {
+                let added = self.plus(b.sum);
+                KahanSum {
+                    sum: added.sum,
+                    compensation: added.compensation + b.compensation,
+                }
+            }
+        }
+
+        impl Monoid for KahanSum<$t> {
+            fn empty() -> Self {
+                Self::new(0.0)
+            }
+        }
+
+        #[cfg(test)]
+        impl quickcheck::Arbitrary for KahanSum<$t> {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                Vec::<$t>::arbitrary(g)
+                    .into_iter()
+                    .filter(|x| x.abs() < <$t>::MAX.sqrt())
+                    .fold(Self::new(0.0), Self::plus)
+            }
+        }
+    )*};
+}
+
+impl_kahan_sum!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::prelude::*;
+
+    #[test]
+    fn recovers_what_naive_summation_loses() {
+        let values = vec![1.0, 1e100, 1.0, -1e100];
+        assert_eq!(values.iter().sum::<f64>(), 0.0);
+        assert_eq!(values.fold_map(KahanSum::from).total(), 2.0);
+    }
+
+    #[test]
+    fn many_small_values_do_not_drift() {
+        let values = vec![0.1f32; 100_000];
+        let naive: f32 = values.iter().sum();
+        let compensated = values.fold_map(KahanSum::from).total();
+        assert!((compensated - 10_000.0).abs() < 0.01);
+        assert!((naive - 10_000.0).abs() > 1.0);
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::{btree_map, hash_map, BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use super::band::Band;
+use super::commutative::{CommutativeMonoid, CommutativeSemigroup};
+use super::monoid::Monoid;
+use super::semigroup::Semigroup;
+
+/// Wraps a map so that `combine` merges the values of colliding keys through
+/// their `Semigroup`, instead of keeping only the right-hand one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergeMap<M>(pub M);
+
+impl<M> MergeMap<M> {
+    pub fn into_inner(self) -> M {
+        self.0
+    }
+}
+
+impl<M> From<M> for MergeMap<M> {
+    fn from(map: M) -> Self {
+        MergeMap(map)
+    }
+}
+
+/// The entry API of the maps `MergeMap` supports.
+trait Entries: IntoIterator<Item = (Self::Key, Self::Value)> {
+    type Key;
+    type Value;
+
+    /// Inserts `v` at `k`, merged with the value already there by `merge`.
+    fn insert_with(
+        &mut self,
+        k: Self::Key,
+        v: Self::Value,
+        merge: impl FnOnce(Self::Value, Self::Value) -> Self::Value,
+    );
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Entries for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    fn insert_with(&mut self, k: K, v: V, merge: impl FnOnce(V, V) -> V) {
+        match self.entry(k) {
+            hash_map::Entry::Occupied(entry) => {
+                let (k, a) = entry.remove_entry();
+                self.insert(k, merge(a, v));
+            }
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(v);
+            }
+        }
+    }
+}
+
+impl<K: Ord, V> Entries for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn insert_with(&mut self, k: K, v: V, merge: impl FnOnce(V, V) -> V) {
+        match self.entry(k) {
+            btree_map::Entry::Occupied(entry) => {
+                let (k, a) = entry.remove_entry();
+                self.insert(k, merge(a, v));
+            }
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(v);
+            }
+        }
+    }
+}
+
+fn merge<M: Entries<Value = V>, V: Semigroup>(a: &mut M, b: M) {
+    for (k, v) in b {
+        a.insert_with(k, v, V::combine);
+    }
+}
+
+impl<K: Eq + Hash, V: Semigroup, S: BuildHasher> Semigroup for MergeMap<HashMap<K, V, S>> {
+    fn combine(mut self, b: Self) -> Self {
+        merge(&mut self.0, b.0);
+        self
+    }
+
+    fn combine_assign(&mut self, b: Self) {
+        merge(&mut self.0, b.0);
+    }
+}
+
+impl<K: Eq + Hash, V: Semigroup, S: BuildHasher + Default> Monoid for MergeMap<HashMap<K, V, S>> {
+    fn empty() -> Self {
+        MergeMap(HashMap::default())
+    }
+}
+
+impl<K: Ord, V: Semigroup> Semigroup for MergeMap<BTreeMap<K, V>> {
+    fn combine(mut self, b: Self) -> Self {
+        merge(&mut self.0, b.0);
+        self
+    }
+
+    fn combine_assign(&mut self, b: Self) {
+        merge(&mut self.0, b.0);
+    }
+}
+
+impl<K: Ord, V: Semigroup> Monoid for MergeMap<BTreeMap<K, V>> {
+    fn empty() -> Self {
+        MergeMap(BTreeMap::new())
+    }
+}
+
+impl<K: Eq + Hash, V: CommutativeSemigroup, S: BuildHasher> CommutativeSemigroup
+    for MergeMap<HashMap<K, V, S>>
+{
+}
+impl<K: Eq + Hash, V: CommutativeSemigroup, S: BuildHasher + Default> CommutativeMonoid
+    for MergeMap<HashMap<K, V, S>>
+{
+}
+impl<K: Ord, V: CommutativeSemigroup> CommutativeSemigroup for MergeMap<BTreeMap<K, V>> {}
+impl<K: Ord, V: CommutativeSemigroup> CommutativeMonoid for MergeMap<BTreeMap<K, V>> {}
+
+impl<K: Eq + Hash, V: Band, S: BuildHasher> Band for MergeMap<HashMap<K, V, S>> {}
+impl<K: Ord, V: Band> Band for MergeMap<BTreeMap<K, V>> {}
+
+#[cfg(test)]
+impl<M: quickcheck::Arbitrary> quickcheck::Arbitrary for MergeMap<M> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        MergeMap(M::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(MergeMap))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colliding_values_are_combined() {
+        let a = MergeMap(HashMap::from([("x", 1), ("y", 2)]));
+        let b = MergeMap(HashMap::from([("y", 40), ("z", 5)]));
+        let expected = HashMap::from([("x", 1), ("y", 42), ("z", 5)]);
+        assert_eq!(a.combine(b).into_inner(), expected);
+    }
+
+    #[test]
+    fn colliding_values_keep_their_order() {
+        let a = MergeMap(BTreeMap::from([(1, "a".to_string())]));
+        let b = MergeMap(BTreeMap::from([(1, "b".to_string())]));
+        assert_eq!(a.combine(b).0[&1], "ab");
+    }
+}
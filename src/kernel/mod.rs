@@ -1,7 +1,40 @@
+pub mod band;
+pub mod checked;
+pub mod commutative;
+pub mod eq;
+pub mod function;
+pub mod group;
+pub mod hash;
+pub mod instance;
+pub mod kahan;
+pub mod merge_map;
 pub mod monoid;
+pub mod order;
+pub mod partial;
+pub mod partial_order;
+pub mod saturating;
 pub mod semigroup;
+pub mod semilattice;
+pub mod wrappers;
 
+// `eq`, `hash`, `order` and `partial_order` are left out of the prelude so
+// their traits don't shadow the std ones of the same name.
 pub mod prelude {
+    #[cfg(feature = "derive")]
+    pub use rats_derive::{Monoid, Partial, Semigroup};
+
+    pub use super::band::*;
+    pub use super::checked::*;
+    pub use super::commutative::*;
+    pub use super::function::*;
+    pub use super::group::*;
+    pub use super::instance::*;
+    pub use super::kahan::*;
+    pub use super::merge_map::*;
     pub use super::monoid::*;
+    pub use super::partial::{Finalize, HasPartial, MissingField, Partial};
+    pub use super::saturating::*;
     pub use super::semigroup::*;
+    pub use super::semilattice::*;
+    pub use super::wrappers::*;
 }
@@ -1,10 +1,41 @@
-use std::collections::{HashMap, HashSet};
-use std::hash::Hash;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
 
 use super::semigroup::Semigroup;
 
 pub trait Monoid: Semigroup {
     fn empty() -> Self;
+
+    fn combine_all(iter: impl IntoIterator<Item = Self>) -> Self
+    where
+        Self: Sized,
+    {
+        iter.into_iter().fold(Self::empty(), Self::combine)
+    }
+
+    fn is_empty(&self) -> bool
+    where
+        Self: Sized + super::eq::Eq,
+    {
+        self.eqv(&Self::empty())
+    }
+
+    /// Combines the elements with `separator` between each adjacent pair.
+    fn intercalate(iter: impl IntoIterator<Item = Self>, separator: Self) -> Self
+    where
+        Self: Sized + Clone,
+    {
+        let mut iter = iter.into_iter();
+        match iter.next() {
+            None => Self::empty(),
+            Some(first) => iter.fold(first, |acc, a| acc.combine(separator.clone()).combine(a)),
+        }
+    }
 }
 
 impl<A: Semigroup> Monoid for Option<A> {
@@ -23,17 +54,102 @@ impl<A> Monoid for Vec<A> {
     fn empty() -> Self {
         Vec::new()
     }
+
+    fn combine_all(iter: impl IntoIterator<Item = Self>) -> Self {
+        let parts: Vec<Vec<A>> = iter.into_iter().collect();
+        let mut combined = Vec::with_capacity(parts.iter().map(Vec::len).sum());
+        for part in parts {
+            combined.extend(part);
+        }
+        combined
+    }
+}
+
+impl Monoid for String {
+    fn empty() -> Self {
+        String::new()
+    }
+
+    fn combine_all(iter: impl IntoIterator<Item = Self>) -> Self {
+        iter.into_iter().collect::<Vec<_>>().concat()
+    }
+}
+
+impl<A> Monoid for VecDeque<A> {
+    fn empty() -> Self {
+        VecDeque::new()
+    }
 }
 
-impl<A: Eq + Hash> Monoid for HashSet<A> {
+impl<A> Monoid for LinkedList<A> {
     fn empty() -> Self {
-        HashSet::new()
+        LinkedList::new()
     }
 }
 
-impl<K: Eq + Hash, V> Monoid for HashMap<K, V> {
+impl<A: Eq + Hash, S: BuildHasher + Default> Monoid for HashSet<A, S> {
     fn empty() -> Self {
-        HashMap::new()
+        HashSet::default()
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> Monoid for HashMap<K, V, S> {
+    fn empty() -> Self {
+        HashMap::default()
+    }
+}
+
+impl<K: Ord, V> Monoid for BTreeMap<K, V> {
+    fn empty() -> Self {
+        BTreeMap::new()
+    }
+}
+
+impl<A: Ord> Monoid for BTreeSet<A> {
+    fn empty() -> Self {
+        BTreeSet::new()
+    }
+}
+
+impl<A: Ord> Monoid for BinaryHeap<A> {
+    fn empty() -> Self {
+        BinaryHeap::new()
+    }
+}
+
+impl<A: Monoid, const N: usize> Monoid for [A; N] {
+    fn empty() -> Self {
+        std::array::from_fn(|_| A::empty())
+    }
+}
+
+impl<A: Monoid> Monoid for Box<A> {
+    fn empty() -> Self {
+        Box::new(A::empty())
+    }
+}
+
+impl<A: Monoid + Clone> Monoid for Rc<A> {
+    fn empty() -> Self {
+        Rc::new(A::empty())
+    }
+}
+
+impl<A: Monoid + Clone> Monoid for Arc<A> {
+    fn empty() -> Self {
+        Arc::new(A::empty())
+    }
+}
+
+impl Monoid for Cow<'_, str> {
+    fn empty() -> Self {
+        Cow::Borrowed("")
+    }
+}
+
+impl Monoid for Ordering {
+    fn empty() -> Self {
+        Ordering::Equal
     }
 }
 
@@ -49,26 +165,119 @@ macro_rules! impl_monoid_defaultable {
 }
 
 impl_monoid_defaultable!(
-    String,
     i8,
     i16,
     i32,
     i64,
+    i128,
+    isize,
     usize,
     u8,
     u16,
     u32,
     u64,
+    u128,
     f32,
     f64,
+    Duration,
     ()
 );
 
+macro_rules! impl_monoid_tuple {
+    ($(($($t:ident),+)),*) => {$(
+        impl<$($t: Monoid),+> Monoid for ($($t,)+) {
+            fn empty() -> Self {
+                ($($t::empty(),)+)
+            }
+        }
+    )*};
+}
+
+impl_monoid_tuple!(
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+    (A, B, C, D, E, F, G, H, I),
+    (A, B, C, D, E, F, G, H, I, J),
+    (A, B, C, D, E, F, G, H, I, J, K),
+    (A, B, C, D, E, F, G, H, I, J, K, L)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[quickcheck]
+    fn combine_all_agrees_with_naive_fold_string(xs: Vec<String>) -> bool {
+        let naive = xs.iter().cloned().fold(String::new(), String::combine);
+        String::combine_all(xs) == naive
+    }
+
+    #[quickcheck]
+    fn combine_all_agrees_with_naive_fold_vec(xs: Vec<Vec<u8>>) -> bool {
+        let naive = xs.iter().cloned().fold(Vec::new(), Vec::combine);
+        Vec::combine_all(xs) == naive
+    }
+
+    #[quickcheck]
+    fn combine_all_agrees_with_naive_fold_option(xs: Vec<Option<u16>>) -> bool {
+        let naive = xs.iter().cloned().fold(None, Option::combine);
+        Option::combine_all(xs) == naive
+    }
+
+    #[quickcheck]
+    fn is_empty_agrees_with_equality(a: Option<u8>, b: i32) -> bool {
+        Monoid::is_empty(&a) == a.is_none() && Monoid::is_empty(&b) == (b == 0)
+    }
+
+    #[quickcheck]
+    fn intercalate_agrees_with_join(xs: Vec<String>, separator: String) -> bool {
+        String::intercalate(xs.clone(), separator.clone()) == xs.join(&separator)
+    }
+
+    #[quickcheck]
+    fn intercalate_agrees_with_naive_fold(xs: Vec<u32>, separator: u32) -> bool {
+        let naive = xs.iter().enumerate().fold(0u32, |acc, (i, x)| {
+            if i == 0 {
+                *x
+            } else {
+                acc.combine(separator).combine(*x)
+            }
+        });
+        u32::intercalate(xs, separator) == naive
+    }
+}
+
 #[cfg(test)]
 mod laws {
-    use std::collections::{HashMap, HashSet};
+    use crate::kernel::checked::Checked;
+    use crate::kernel::kahan::KahanSum;
+    use crate::kernel::merge_map::MergeMap;
+    use crate::kernel::saturating::Saturating;
+    use crate::kernel::wrappers::*;
+    use std::borrow::Cow;
+    use std::cmp::Ordering;
+    use std::collections::{
+        BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque,
+    };
+    use std::rc::Rc;
+    use std::sync::Arc;
+    use std::time::Duration;
 
     macro_rules! left_identity {
+        (tuple $($t:ident),*) => {$(
+            paste::paste! {
+                left_identity!(
+                    [<left_identity_ $t>]: $t,
+                    from crate::testing::ArbitraryTuple<$t>,
+                    |t: crate::testing::ArbitraryTuple<$t>| t.0
+                );
+            }
+        )*};
         ($($t:ty),*) => {$(
             paste::paste! {
                 left_identity!([<left_identity_ $t>]: $t);
@@ -110,6 +319,27 @@ mod laws {
                 }
             }
         };
+        ($name:ident: $t:ty, by $f:expr) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t) -> bool {
+                use crate::kernel::prelude::*;
+                let n1_copy = n1.clone();
+
+                ($f)(<$t as Monoid>::empty().combine(n1)) == ($f)(n1_copy)
+            }
+        };
+        ($name:ident: $t:ty, from $s:ty, $f:expr) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(s1: $s) -> bool {
+                use crate::kernel::prelude::*;
+                let n1: $t = ($f)(s1);
+                let n1_copy = n1.clone();
+
+                <$t as Monoid>::empty().combine(n1) == n1_copy
+            }
+        };
         ($name:ident: $t:ty) => {
             #[allow(non_snake_case)]
             #[quickcheck]
@@ -123,6 +353,15 @@ mod laws {
     }
 
     macro_rules! right_identity {
+        (tuple $($t:ident),*) => {$(
+            paste::paste! {
+                right_identity!(
+                    [<right_identity_ $t>]: $t,
+                    from crate::testing::ArbitraryTuple<$t>,
+                    |t: crate::testing::ArbitraryTuple<$t>| t.0
+                );
+            }
+        )*};
         ($($t:ty),*) => {$(
             paste::paste! {
                 right_identity!([<right_identity_ $t>]: $t);
@@ -164,6 +403,27 @@ mod laws {
                 }
             }
         };
+        ($name:ident: $t:ty, by $f:expr) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t) -> bool {
+                use crate::kernel::prelude::*;
+                let n1_copy = n1.clone();
+
+                ($f)(n1.combine(<$t as Monoid>::empty())) == ($f)(n1_copy)
+            }
+        };
+        ($name:ident: $t:ty, from $s:ty, $f:expr) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(s1: $s) -> bool {
+                use crate::kernel::prelude::*;
+                let n1: $t = ($f)(s1);
+                let n1_copy = n1.clone();
+
+                n1.combine(<$t as Monoid>::empty()) == n1_copy
+            }
+        };
         ($name:ident: $t:ty) => {
             #[allow(non_snake_case)]
             #[quickcheck]
@@ -182,9 +442,109 @@ mod laws {
     type OptionUsize = Option<usize>;
     type ResultUsizeUsize = Result<usize, usize>;
     type Unit = ();
+    type SumU8 = Sum<u8>;
+    type ProductI32 = Product<i32>;
+    type MinI64 = Min<i64>;
+    type MaxU16 = Max<u16>;
+    type MaxString = Max<String>;
+    type MaxOptionUsize = Max<Option<usize>>;
+    type DualString = Dual<String>;
+    type MergeHashMapUsizeString = MergeMap<HashMap<usize, String>>;
+    type MergeBTreeMapU8VecU8 = MergeMap<BTreeMap<u8, Vec<u8>>>;
+    type VecDequeU8 = VecDeque<u8>;
+    type LinkedListU8 = LinkedList<u8>;
+    type BTreeMapU8String = BTreeMap<u8, String>;
+    type BTreeSetU8 = BTreeSet<u8>;
+    type BinaryHeapU8 = BinaryHeap<u8>;
+    type BoxString = Box<String>;
+    type RcString = Rc<String>;
+    type ArcString = Arc<String>;
+    type CowStr = Cow<'static, str>;
+    type ArrayString3 = [String; 3];
+    type CheckedSumU8 = Checked<Sum<u8>>;
+    type CheckedSumU16 = Checked<Sum<u16>>;
+    type CheckedSumU32 = Checked<Sum<u32>>;
+    type CheckedSumU64 = Checked<Sum<u64>>;
+    type CheckedSumU128 = Checked<Sum<u128>>;
+    type CheckedSumUsize = Checked<Sum<usize>>;
+    type SaturatingSumU8 = Saturating<Sum<u8>>;
+    type SaturatingSumU16 = Saturating<Sum<u16>>;
+    type SaturatingSumU32 = Saturating<Sum<u32>>;
+    type SaturatingSumU64 = Saturating<Sum<u64>>;
+    type SaturatingSumU128 = Saturating<Sum<u128>>;
+    type SaturatingSumUsize = Saturating<Sum<usize>>;
+    type SaturatingProductU8 = Saturating<Product<u8>>;
+    type SaturatingProductU16 = Saturating<Product<u16>>;
+    type SaturatingProductU32 = Saturating<Product<u32>>;
+    type SaturatingProductU64 = Saturating<Product<u64>>;
+    type SaturatingProductU128 = Saturating<Product<u128>>;
+    type SaturatingProductUsize = Saturating<Product<usize>>;
+    type KahanSumF32 = KahanSum<f32>;
+    type KahanSumF64 = KahanSum<f64>;
+    type EitherStringUsize = crate::data::either::Either<String, usize>;
+    type Tuple2 = (usize, String);
+    type Tuple3 = (Vec<u8>, Option<u16>, i32);
+    type Tuple4 = (String, Vec<u8>, Option<u16>, Unit);
+    type Tuple5 = (u8, String, Min<i64>, Unit, HashSet<u8>);
+    type Tuple6 = (u8, String, Vec<u8>, Option<u16>, Max<u32>, Unit);
+    type Tuple7 = (u8, String, Vec<u8>, Option<u16>, Max<u32>, Unit, All);
+    type Tuple8 = (u8, u16, u32, u64, i8, i16, i32, i64);
+    type Tuple9 = (u8, u16, u32, u64, i8, i16, i32, i64, String);
+    type Tuple10 = (u8, u16, u32, u64, i8, i16, i32, i64, String, Vec<u8>);
+    type Tuple11 = (
+        u8,
+        u16,
+        u32,
+        u64,
+        i8,
+        i16,
+        i32,
+        i64,
+        String,
+        Vec<u8>,
+        Option<u16>,
+    );
+    type Tuple12 = (
+        u8,
+        u16,
+        u32,
+        u64,
+        i8,
+        i16,
+        i32,
+        i64,
+        String,
+        Vec<u8>,
+        Option<u16>,
+        Any,
+    );
 
     mod left_identity {
         use super::*;
+        left_identity!(
+            tuple Tuple2,
+            Tuple3,
+            Tuple4,
+            Tuple5,
+            Tuple6,
+            Tuple7,
+            Tuple8,
+            Tuple9,
+            Tuple10,
+            Tuple11,
+            Tuple12
+        );
+        left_identity!(
+            left_identity_BinaryHeapU8: BinaryHeapU8,
+            by BinaryHeap::into_sorted_vec
+        );
+        left_identity!(left_identity_RcString: RcString, from String, Rc::new);
+        left_identity!(left_identity_CowStr: CowStr, from String, Cow::Owned);
+        left_identity!(
+            left_identity_Ordering: Ordering,
+            from i8,
+            |n: i8| n.cmp(&0)
+        );
         left_identity!(
             usize,
             u64,
@@ -196,12 +556,79 @@ mod laws {
             OptionUsize,
             ResultUsizeUsize,
             f64,
-            f32
+            f32,
+            SumU8,
+            ProductI32,
+            MinI64,
+            MaxU16,
+            MaxString,
+            MaxOptionUsize,
+            All,
+            Any,
+            DualString,
+            MergeHashMapUsizeString,
+            MergeBTreeMapU8VecU8,
+            i128,
+            u128,
+            isize,
+            Duration,
+            VecDequeU8,
+            LinkedListU8,
+            BTreeMapU8String,
+            BTreeSetU8,
+            BoxString,
+            ArcString,
+            ArrayString3,
+            CheckedSumU8,
+            CheckedSumU16,
+            CheckedSumU32,
+            CheckedSumU64,
+            CheckedSumU128,
+            CheckedSumUsize,
+            SaturatingSumU8,
+            SaturatingSumU16,
+            SaturatingSumU32,
+            SaturatingSumU64,
+            SaturatingSumU128,
+            SaturatingSumUsize,
+            SaturatingProductU8,
+            SaturatingProductU16,
+            SaturatingProductU32,
+            SaturatingProductU64,
+            SaturatingProductU128,
+            SaturatingProductUsize,
+            KahanSumF32,
+            KahanSumF64,
+            EitherStringUsize
         );
     }
 
     mod right_identity {
         use super::*;
+        right_identity!(
+            tuple Tuple2,
+            Tuple3,
+            Tuple4,
+            Tuple5,
+            Tuple6,
+            Tuple7,
+            Tuple8,
+            Tuple9,
+            Tuple10,
+            Tuple11,
+            Tuple12
+        );
+        right_identity!(
+            right_identity_BinaryHeapU8: BinaryHeapU8,
+            by BinaryHeap::into_sorted_vec
+        );
+        right_identity!(right_identity_RcString: RcString, from String, Rc::new);
+        right_identity!(right_identity_CowStr: CowStr, from String, Cow::Owned);
+        right_identity!(
+            right_identity_Ordering: Ordering,
+            from i8,
+            |n: i8| n.cmp(&0)
+        );
         right_identity!(
             usize,
             u64,
@@ -213,7 +640,50 @@ mod laws {
             OptionUsize,
             ResultUsizeUsize,
             f64,
-            f32
+            f32,
+            SumU8,
+            ProductI32,
+            MinI64,
+            MaxU16,
+            MaxString,
+            MaxOptionUsize,
+            All,
+            Any,
+            DualString,
+            MergeHashMapUsizeString,
+            MergeBTreeMapU8VecU8,
+            i128,
+            u128,
+            isize,
+            Duration,
+            VecDequeU8,
+            LinkedListU8,
+            BTreeMapU8String,
+            BTreeSetU8,
+            BoxString,
+            ArcString,
+            ArrayString3,
+            CheckedSumU8,
+            CheckedSumU16,
+            CheckedSumU32,
+            CheckedSumU64,
+            CheckedSumU128,
+            CheckedSumUsize,
+            SaturatingSumU8,
+            SaturatingSumU16,
+            SaturatingSumU32,
+            SaturatingSumU64,
+            SaturatingSumU128,
+            SaturatingSumUsize,
+            SaturatingProductU8,
+            SaturatingProductU16,
+            SaturatingProductU32,
+            SaturatingProductU64,
+            SaturatingProductU128,
+            SaturatingProductUsize,
+            KahanSumF32,
+            KahanSumF64,
+            EitherStringUsize
         );
     }
 }
@@ -0,0 +1,401 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
+
+use super::monoid::Monoid;
+use super::partial_order::PartialOrder;
+use super::semigroup::Semigroup;
+
+pub trait Order: PartialOrder {
+    fn compare(&self, other: &Self) -> Ordering;
+
+    fn min(x: Self, y: Self) -> Self
+    where
+        Self: Sized,
+    {
+        match x.compare(&y) {
+            Ordering::Greater => y,
+            _ => x,
+        }
+    }
+
+    fn max(x: Self, y: Self) -> Self
+    where
+        Self: Sized,
+    {
+        match x.compare(&y) {
+            Ordering::Less => y,
+            _ => x,
+        }
+    }
+}
+
+macro_rules! impl_order_from_std {
+    ($($t:ty),*) => {$(
+        impl Order for $t {
+            fn compare(&self, other: &Self) -> Ordering {
+                self.cmp(other)
+            }
+        }
+    )*};
+}
+
+impl_order_from_std!(
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    str,
+    String,
+    ()
+);
+
+impl Order for f32 {
+    fn compare(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+impl Order for f64 {
+    fn compare(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+impl<A: Order + ?Sized> Order for &A {
+    fn compare(&self, other: &Self) -> Ordering {
+        (**self).compare(*other)
+    }
+}
+
+impl<A: Order + ?Sized> Order for Box<A> {
+    fn compare(&self, other: &Self) -> Ordering {
+        (**self).compare(&**other)
+    }
+}
+
+impl<A: Order> Order for Option<A> {
+    fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Some(a), Some(b)) => a.compare(b),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+        }
+    }
+}
+
+impl<A: Order, E: Order> Order for Result<A, E> {
+    fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Ok(a), Ok(b)) => a.compare(b),
+            (Err(a), Err(b)) => a.compare(b),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+        }
+    }
+}
+
+pub(crate) fn compare_iter<'a, A: Order + 'a>(
+    a: impl IntoIterator<Item = &'a A>,
+    b: impl IntoIterator<Item = &'a A>,
+) -> Ordering {
+    compare_iter_by(a, b, |a, b| a.compare(b))
+}
+
+fn compare_iter_by<T>(
+    a: impl IntoIterator<Item = T>,
+    b: impl IntoIterator<Item = T>,
+    mut compare: impl FnMut(T, T) -> Ordering,
+) -> Ordering {
+    let mut b = b.into_iter();
+    for a in a {
+        match b.next() {
+            None => return Ordering::Greater,
+            Some(b) => match compare(a, b) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            },
+        }
+    }
+    match b.next() {
+        None => Ordering::Equal,
+        Some(_) => Ordering::Less,
+    }
+}
+
+impl<A: Order> Order for [A] {
+    fn compare(&self, other: &Self) -> Ordering {
+        compare_iter(self, other)
+    }
+}
+
+impl<A: Order, const N: usize> Order for [A; N] {
+    fn compare(&self, other: &Self) -> Ordering {
+        compare_iter(self, other)
+    }
+}
+
+impl<A: Order> Order for Vec<A> {
+    fn compare(&self, other: &Self) -> Ordering {
+        compare_iter(self, other)
+    }
+}
+
+impl<A: Order> Order for VecDeque<A> {
+    fn compare(&self, other: &Self) -> Ordering {
+        compare_iter(self, other)
+    }
+}
+
+impl<A: Order> Order for LinkedList<A> {
+    fn compare(&self, other: &Self) -> Ordering {
+        compare_iter(self, other)
+    }
+}
+
+impl<A: Order> Order for BTreeSet<A> {
+    fn compare(&self, other: &Self) -> Ordering {
+        compare_iter(self, other)
+    }
+}
+
+impl<K: Order, V: Order> Order for BTreeMap<K, V> {
+    fn compare(&self, other: &Self) -> Ordering {
+        compare_iter_by(self, other, |(k, v), (other_k, other_v)| {
+            k.compare(other_k).then_with(|| v.compare(other_v))
+        })
+    }
+}
+
+macro_rules! impl_order_tuple {
+    ($(($($t:ident $i:tt),+)),*) => {$(
+        impl<$($t: Order),+> Order for ($($t,)+) {
+            fn compare(&self, other: &Self) -> Ordering {
+                $(
+                    match self.$i.compare(&other.$i) {
+                        Ordering::Equal => {}
+                        ordering => return ordering,
+                    }
+                )+
+                Ordering::Equal
+            }
+        }
+    )*};
+}
+
+impl_order_tuple!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+);
+
+type CompareFn<A> = dyn Fn(&A, &A) -> Ordering;
+
+/// A comparison function, usable where a type's own [`Order`] isn't the one
+/// wanted.
+///
+/// Comparisons combine lexicographically: the second one only breaks the ties
+/// of the first, and the empty comparison considers everything equal.
+pub struct OrderBy<A> {
+    compare: Box<CompareFn<A>>,
+}
+
+impl<A: 'static> OrderBy<A> {
+    pub fn from_fn(compare: impl Fn(&A, &A) -> Ordering + 'static) -> Self {
+        OrderBy {
+            compare: Box::new(compare),
+        }
+    }
+
+    pub fn natural() -> Self
+    where
+        A: Order,
+    {
+        OrderBy::from_fn(A::compare)
+    }
+
+    pub fn by<B: Order>(f: impl Fn(&A) -> B + 'static) -> Self {
+        OrderBy::from_fn(move |x, y| f(x).compare(&f(y)))
+    }
+
+    pub fn reverse(self) -> Self {
+        OrderBy::from_fn(move |x, y| (self.compare)(y, x))
+    }
+
+    pub fn compare(&self, x: &A, y: &A) -> Ordering {
+        (self.compare)(x, y)
+    }
+
+    pub fn min<'a>(&self, x: &'a A, y: &'a A) -> &'a A {
+        match self.compare(x, y) {
+            Ordering::Greater => y,
+            _ => x,
+        }
+    }
+
+    pub fn max<'a>(&self, x: &'a A, y: &'a A) -> &'a A {
+        match self.compare(x, y) {
+            Ordering::Less => y,
+            _ => x,
+        }
+    }
+}
+
+impl<A: 'static> Semigroup for OrderBy<A> {
+    fn combine(self, b: Self) -> Self {
+        OrderBy::from_fn(move |x, y| self.compare(x, y).then_with(|| b.compare(x, y)))
+    }
+}
+
+impl<A: 'static> Monoid for OrderBy<A> {
+    fn empty() -> Self {
+        OrderBy::from_fn(|_, _| Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Person {
+        name: String,
+        age: u32,
+    }
+
+    fn person(name: &str, age: u32) -> Person {
+        Person {
+            name: name.to_string(),
+            age,
+        }
+    }
+
+    #[test]
+    fn min_and_max() {
+        assert_eq!(Order::min(1, 2), 1);
+        assert_eq!(Order::max(1, 2), 2);
+        assert_eq!(Order::max(f64::NAN, 1.0).to_bits(), f64::NAN.to_bits());
+    }
+
+    #[test]
+    fn order_by_contramaps() {
+        let by_age = OrderBy::by(|p: &Person| p.age);
+        let young = person("b", 20);
+        let old = person("a", 30);
+        assert_eq!(by_age.compare(&young, &old), Ordering::Less);
+        assert_eq!(by_age.max(&young, &old), &old);
+        assert_eq!(by_age.reverse().min(&young, &old), &old);
+    }
+
+    #[test]
+    fn order_by_combines_lexicographically() {
+        let order =
+            OrderBy::by(|p: &Person| p.age).combine(OrderBy::by(|p: &Person| p.name.clone()));
+        let mut people = vec![person("c", 30), person("b", 20), person("a", 30)];
+        people.sort_by(|x, y| order.compare(x, y));
+        assert_eq!(
+            people,
+            vec![person("b", 20), person("a", 30), person("c", 30)]
+        );
+    }
+
+    #[test]
+    fn empty_order_by_is_identity() {
+        let order = OrderBy::<Person>::empty().combine(OrderBy::by(|p: &Person| p.age));
+        assert_eq!(
+            order.compare(&person("a", 30), &person("b", 20)),
+            Ordering::Greater
+        );
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use std::collections::BTreeMap;
+
+    macro_rules! order_laws {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                order_laws!([<totality_ $t>], [<transitivity_ $t>], [<consistent_with_partial_ $t>]: $t);
+            }
+        )*};
+        ($totality:ident, $transitivity:ident, $consistency:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $totality(n1: $t, n2: $t) -> bool {
+                use crate::kernel::partial_order::PartialOrder;
+
+                n1.lteqv(&n2) || n2.lteqv(&n1)
+            }
+
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $transitivity(n1: $t, n2: $t, n3: $t) -> bool {
+                use crate::kernel::order::Order;
+                use std::cmp::Ordering;
+
+                !(n1.compare(&n2) != Ordering::Greater && n2.compare(&n3) != Ordering::Greater)
+                    || n1.compare(&n3) != Ordering::Greater
+            }
+
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $consistency(n1: $t, n2: $t) -> bool {
+                use crate::kernel::order::Order;
+                use crate::kernel::partial_order::PartialOrder;
+
+                n1.partial_compare(&n2) == Some(n1.compare(&n2))
+                    && n1.compare(&n2) == n2.compare(&n1).reverse()
+            }
+        };
+    }
+
+    type OptionU8 = Option<u8>;
+    type ResultU8Bool = Result<u8, bool>;
+    type VecU8 = Vec<u8>;
+    type TupleU8BoolString = (u8, bool, String);
+    type BTreeMapU8U8 = BTreeMap<u8, u8>;
+
+    order_laws!(
+        bool,
+        u8,
+        i64,
+        f32,
+        f64,
+        String,
+        OptionU8,
+        ResultU8Bool,
+        VecU8,
+        TupleU8BoolString,
+        BTreeMapU8U8
+    );
+
+    #[quickcheck]
+    fn compare_consistent_with_std(
+        a: Vec<(i32, Option<String>)>,
+        b: Vec<(i32, Option<String>)>,
+    ) -> bool {
+        use crate::kernel::order::Order;
+
+        a.compare(&b) == a.cmp(&b)
+    }
+}
@@ -0,0 +1,79 @@
+use std::error::Error;
+use std::fmt;
+
+use super::monoid::Monoid;
+
+/// The all-`Option` companion of `T` generated by `#[derive(Partial)]`.
+pub type Partial<T> = <T as HasPartial>::Partial;
+
+pub trait HasPartial: Sized {
+    type Partial: Finalize<Target = Self>;
+}
+
+/// A layer of `Target` whose fields may be unset. Combining layers lets each
+/// field of the later one override the earlier one.
+pub trait Finalize: Monoid {
+    type Target;
+
+    /// Builds the target, pushing every unset required field onto `missing`
+    /// with its name prefixed by `path`.
+    fn finalize_at(self, path: &str, missing: &mut Vec<MissingField>) -> Option<Self::Target>;
+
+    fn finalize(self) -> Result<Self::Target, Vec<MissingField>>
+    where
+        Self: Sized,
+    {
+        let mut missing = Vec::new();
+        match self.finalize_at("", &mut missing) {
+            Some(target) if missing.is_empty() => Ok(target),
+            _ => Err(missing),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MissingField {
+    pub path: String,
+}
+
+impl MissingField {
+    /// The field `field` of the value found at `path`.
+    pub fn at(path: &str, field: &str) -> Self {
+        MissingField {
+            path: join(path, field),
+        }
+    }
+}
+
+impl fmt::Display for MissingField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing required field `{}`", self.path)
+    }
+}
+
+impl Error for MissingField {}
+
+/// Appends `field` to the dotted `path` of its parent. Used by the code
+/// `#[derive(Partial)]` generates; not part of the public API.
+#[doc(hidden)]
+pub fn join(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_paths_are_dotted() {
+        assert_eq!(MissingField::at("", "port").path, "port");
+        assert_eq!(
+            MissingField::at("server.tls", "cert").to_string(),
+            "missing required field `server.tls.cert`"
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashSet, LinkedList, VecDeque};
+use std::hash::BuildHasher;
+
+use super::eq::Eq;
+
+pub trait PartialOrder: Eq {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering>;
+
+    fn pmin(x: Self, y: Self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match x.partial_compare(&y)? {
+            Ordering::Greater => Some(y),
+            _ => Some(x),
+        }
+    }
+
+    fn pmax(x: Self, y: Self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match x.partial_compare(&y)? {
+            Ordering::Less => Some(y),
+            _ => Some(x),
+        }
+    }
+
+    fn lteqv(&self, other: &Self) -> bool {
+        matches!(
+            self.partial_compare(other),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    fn gteqv(&self, other: &Self) -> bool {
+        matches!(
+            self.partial_compare(other),
+            Some(Ordering::Greater | Ordering::Equal)
+        )
+    }
+}
+
+macro_rules! impl_partial_order_from_std {
+    ($($t:ty),*) => {$(
+        impl PartialOrder for $t {
+            fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    )*};
+}
+
+impl_partial_order_from_std!(
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    str,
+    String,
+    ()
+);
+
+impl PartialOrder for f32 {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        Some(self.total_cmp(other))
+    }
+}
+
+impl PartialOrder for f64 {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        Some(self.total_cmp(other))
+    }
+}
+
+impl<A: PartialOrder + ?Sized> PartialOrder for &A {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_compare(*other)
+    }
+}
+
+impl<A: PartialOrder + ?Sized> PartialOrder for Box<A> {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_compare(&**other)
+    }
+}
+
+impl<A: PartialOrder> PartialOrder for Option<A> {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Some(a), Some(b)) => a.partial_compare(b),
+            (None, None) => Some(Ordering::Equal),
+            (None, Some(_)) => Some(Ordering::Less),
+            (Some(_), None) => Some(Ordering::Greater),
+        }
+    }
+}
+
+impl<A: PartialOrder, E: PartialOrder> PartialOrder for Result<A, E> {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Ok(a), Ok(b)) => a.partial_compare(b),
+            (Err(a), Err(b)) => a.partial_compare(b),
+            (Ok(_), Err(_)) => Some(Ordering::Less),
+            (Err(_), Ok(_)) => Some(Ordering::Greater),
+        }
+    }
+}
+
+pub(crate) fn partial_compare_iter<'a, A: PartialOrder + 'a>(
+    a: impl IntoIterator<Item = &'a A>,
+    b: impl IntoIterator<Item = &'a A>,
+) -> Option<Ordering> {
+    partial_compare_iter_by(a, b, |a, b| a.partial_compare(b))
+}
+
+fn partial_compare_iter_by<T>(
+    a: impl IntoIterator<Item = T>,
+    b: impl IntoIterator<Item = T>,
+    mut partial_compare: impl FnMut(T, T) -> Option<Ordering>,
+) -> Option<Ordering> {
+    let mut b = b.into_iter();
+    for a in a {
+        match b.next() {
+            None => return Some(Ordering::Greater),
+            Some(b) => match partial_compare(a, b)? {
+                Ordering::Equal => {}
+                ordering => return Some(ordering),
+            },
+        }
+    }
+    match b.next() {
+        None => Some(Ordering::Equal),
+        Some(_) => Some(Ordering::Less),
+    }
+}
+
+impl<A: PartialOrder> PartialOrder for [A] {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        partial_compare_iter(self, other)
+    }
+}
+
+impl<A: PartialOrder, const N: usize> PartialOrder for [A; N] {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        partial_compare_iter(self, other)
+    }
+}
+
+impl<A: PartialOrder> PartialOrder for Vec<A> {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        partial_compare_iter(self, other)
+    }
+}
+
+impl<A: PartialOrder> PartialOrder for VecDeque<A> {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        partial_compare_iter(self, other)
+    }
+}
+
+impl<A: PartialOrder> PartialOrder for LinkedList<A> {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        partial_compare_iter(self, other)
+    }
+}
+
+impl<A: PartialOrder> PartialOrder for BTreeSet<A> {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        partial_compare_iter(self, other)
+    }
+}
+
+impl<K: PartialOrder, V: PartialOrder> PartialOrder for BTreeMap<K, V> {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        partial_compare_iter_by(self, other, |(ka, va), (kb, vb)| {
+            match ka.partial_compare(kb)? {
+                Ordering::Equal => va.partial_compare(vb),
+                ordering => Some(ordering),
+            }
+        })
+    }
+}
+
+// Sets are ordered by inclusion, so two sets with elements the other lacks
+// are not comparable.
+impl<A: std::cmp::Eq + std::hash::Hash, S: BuildHasher> PartialOrder for HashSet<A, S> {
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        match self.len().cmp(&other.len()) {
+            Ordering::Less if self.is_subset(other) => Some(Ordering::Less),
+            Ordering::Greater if self.is_superset(other) => Some(Ordering::Greater),
+            Ordering::Equal if self == other => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_partial_order_tuple {
+    ($(($($t:ident $i:tt),+)),*) => {$(
+        impl<$($t: PartialOrder),+> PartialOrder for ($($t,)+) {
+            fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+                $(
+                    match self.$i.partial_compare(&other.$i)? {
+                        Ordering::Equal => {}
+                        ordering => return Some(ordering),
+                    }
+                )+
+                Some(Ordering::Equal)
+            }
+        }
+    )*};
+}
+
+impl_partial_order_tuple!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+);
+
+#[cfg(test)]
+mod laws {
+    use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+    macro_rules! partial_order_laws {
+        ($($t:ty),*) => {$(
+            paste::paste! {
+                partial_order_laws!(
+                    [<reflexivity_ $t>],
+                    [<antisymmetry_ $t>],
+                    [<transitivity_ $t>],
+                    [<consistent_with_eqv_ $t>]: $t
+                );
+            }
+        )*};
+        ($reflexivity:ident, $antisymmetry:ident, $transitivity:ident, $consistency:ident: $t:ty) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $reflexivity(n1: $t) -> bool {
+                use crate::kernel::partial_order::PartialOrder;
+
+                n1.lteqv(&n1) && n1.gteqv(&n1)
+            }
+
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $antisymmetry(n1: $t, n2: $t) -> bool {
+                use crate::kernel::eq::Eq;
+                use crate::kernel::partial_order::PartialOrder;
+
+                !(n1.lteqv(&n2) && n2.lteqv(&n1)) || n1.eqv(&n2)
+            }
+
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $transitivity(n1: $t, n2: $t, n3: $t) -> bool {
+                use crate::kernel::partial_order::PartialOrder;
+
+                !(n1.lteqv(&n2) && n2.lteqv(&n3)) || n1.lteqv(&n3)
+            }
+
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $consistency(n1: $t, n2: $t) -> bool {
+                use crate::kernel::eq::Eq;
+                use crate::kernel::partial_order::PartialOrder;
+                use std::cmp::Ordering;
+
+                n1.eqv(&n2) == (n1.partial_compare(&n2) == Some(Ordering::Equal))
+            }
+        };
+    }
+
+    type OptionU8 = Option<u8>;
+    type VecU8 = Vec<u8>;
+    type TupleU8Bool = (u8, bool);
+    type BTreeSetU8 = BTreeSet<u8>;
+    type BTreeMapU8U8 = BTreeMap<u8, u8>;
+    type HashSetU8 = HashSet<u8>;
+
+    partial_order_laws!(
+        u8,
+        i64,
+        f64,
+        String,
+        OptionU8,
+        VecU8,
+        TupleU8Bool,
+        BTreeSetU8,
+        BTreeMapU8U8,
+        HashSetU8
+    );
+
+    #[quickcheck]
+    fn btree_maps_compare_like_std(a: BTreeMap<u8, u8>, b: BTreeMap<u8, u8>) -> bool {
+        use crate::kernel::partial_order::PartialOrder;
+
+        a.partial_compare(&b) == a.partial_cmp(&b)
+    }
+
+    #[test]
+    fn hash_sets_are_ordered_by_inclusion() {
+        use crate::kernel::partial_order::PartialOrder;
+        use std::cmp::Ordering;
+
+        let small: HashSet<u8> = [1].iter().cloned().collect();
+        let big: HashSet<u8> = [1, 2].iter().cloned().collect();
+        let other: HashSet<u8> = [3].iter().cloned().collect();
+        assert_eq!(small.partial_compare(&big), Some(Ordering::Less));
+        assert_eq!(big.partial_compare(&small), Some(Ordering::Greater));
+        assert_eq!(small.partial_compare(&other), None);
+        assert_eq!(
+            HashSet::pmin(small.clone(), big.clone()),
+            Some(small.clone())
+        );
+        assert_eq!(HashSet::pmax(small.clone(), other), None);
+    }
+}
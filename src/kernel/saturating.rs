@@ -0,0 +1,157 @@
+use super::monoid::Monoid;
+use super::semigroup::Semigroup;
+use super::wrappers::{Product, Sum};
+
+/// Arithmetic that clamps at the bounds of the integer type instead of
+/// wrapping around.
+///
+/// Over unsigned integers both sums and products are associative, so only
+/// those are instances. Signed ones are not: `(MAX + 1) + -1` clamps to
+/// `MAX - 1` while `MAX + (1 + -1)` stays at `MAX`; they are available as the
+/// plain `saturating_add` and `saturating_mul` instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Saturating<A>(pub A);
+
+impl<A> Saturating<A> {
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+impl<A> From<A> for Saturating<A> {
+    fn from(a: A) -> Self {
+        Saturating(a)
+    }
+}
+
+macro_rules! impl_saturating {
+    ($($t:ty),*) => {$(
+        impl Semigroup for Saturating<Sum<$t>> {
+            fn combine(self, b: Self) -> Self {
+                Saturating(Sum((self.0).0.saturating_add((b.0).0)))
+            }
+        }
+
+        impl Monoid for Saturating<Sum<$t>> {
+            fn empty() -> Self {
+                Saturating(Sum(0))
+            }
+        }
+
+        impl Semigroup for Saturating<Product<$t>> {
+            fn combine(self, b: Self) -> Self {
+                Saturating(Product((self.0).0.saturating_mul((b.0).0)))
+            }
+        }
+
+        impl Monoid for Saturating<Product<$t>> {
+            fn empty() -> Self {
+                Saturating(Product(1))
+            }
+        }
+    )*};
+}
+
+impl_saturating!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_saturating_signed {
+    ($($t:ty),*) => {$(
+        impl Saturating<Sum<$t>> {
+            /// Not associative, see [`Saturating`].
+            pub fn saturating_add(self, b: Self) -> Self {
+                Saturating(Sum((self.0).0.saturating_add((b.0).0)))
+            }
+        }
+
+        impl Saturating<Product<$t>> {
+            /// Not associative, see [`Saturating`].
+            pub fn saturating_mul(self, b: Self) -> Self {
+                Saturating(Product((self.0).0.saturating_mul((b.0).0)))
+            }
+        }
+    )*};
+}
+
+impl_saturating_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+use super::checked::boundary;
+
+#[cfg(test)]
+macro_rules! impl_arbitrary_saturating {
+    ($($t:ty: $gen:ident),*) => {$(
+        impl quickcheck::Arbitrary for Saturating<Sum<$t>> {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                Saturating(Sum(boundary::$gen(g)))
+            }
+        }
+
+        impl quickcheck::Arbitrary for Saturating<Product<$t>> {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                Saturating(Product(boundary::$gen(g)))
+            }
+        }
+    )*};
+}
+
+#[cfg(test)]
+impl_arbitrary_saturating!(
+    u8: near_bounds_u8,
+    u16: near_bounds_u16,
+    u32: near_bounds_u32,
+    u64: near_bounds_u64,
+    u128: near_bounds_u128,
+    usize: near_bounds_usize
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::prelude::*;
+
+    #[test]
+    fn clamps_at_the_bounds() {
+        assert_eq!(
+            Saturating(Sum(u8::MAX)).combine(Saturating(Sum(1))),
+            Saturating(Sum(u8::MAX))
+        );
+        assert_eq!(
+            Saturating(Sum(i16::MIN)).saturating_add(Saturating(Sum(-1))),
+            Saturating(Sum(i16::MIN))
+        );
+        assert_eq!(
+            Saturating(Product(i32::MIN)).saturating_mul(Saturating(Product(2))),
+            Saturating(Product(i32::MIN))
+        );
+        assert_eq!(
+            Saturating(Product(i64::MIN)).saturating_mul(Saturating(Product(-1))),
+            Saturating(Product(i64::MAX))
+        );
+    }
+
+    #[test]
+    fn counters_stop_at_max() {
+        let counts = vec![u64::MAX - 1, 5, 7];
+        assert_eq!(
+            counts.fold_map(|n| Saturating(Sum(n))).into_inner(),
+            Sum(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn signed_sums_are_not_associative() {
+        let (a, b, c) = (
+            Saturating(Sum(i8::MAX)),
+            Saturating(Sum(1)),
+            Saturating(Sum(-1)),
+        );
+        assert_eq!(
+            a.saturating_add(b).saturating_add(c),
+            Saturating(Sum(i8::MAX - 1))
+        );
+        assert_eq!(
+            a.saturating_add(b.saturating_add(c)),
+            Saturating(Sum(i8::MAX))
+        );
+    }
+}
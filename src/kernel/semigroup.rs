@@ -1,22 +1,143 @@
-use std::collections::{HashMap, HashSet};
-use std::hash::Hash;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::num::NonZeroUsize;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::monoid::Monoid;
+use super::wrappers::Dual;
 
 pub trait Semigroup {
     fn combine(self, b: Self) -> Self;
+
+    /// Combines `self` with itself `n` times, in `O(log n)` combines. See
+    /// [`Group::combine_n`](super::group::Group::combine_n) for any `n`.
+    fn combine_n_nonzero(self, n: NonZeroUsize) -> Self
+    where
+        Self: Sized + Clone,
+    {
+        repeated_squaring(self, n.get() as u64)
+    }
+
+    /// Combines without consuming either argument. `String` copies both sides
+    /// straight into one allocation. `Vec`, `VecDeque`, `HashMap`, `BTreeMap`
+    /// and the other collections generic over their elements still clone `b`
+    /// as a whole: `Self: Clone` doesn't give their impls `A: Clone`, and an
+    /// impl can't add that bound to this method, so `extend_from_slice` and
+    /// per-element clones are out of reach.
+    fn combine_ref(&self, b: &Self) -> Self
+    where
+        Self: Sized + Clone,
+    {
+        self.clone().combine(b.clone())
+    }
+
+    /// Combines `b` into `self` in place. The default swaps `self` out for
+    /// `Monoid::empty`; collections override it to append directly. Types
+    /// without an empty value, like `Min` and `NonEmptyVec`, have an
+    /// inherent `combine_assign` instead.
+    fn combine_assign(&mut self, b: Self)
+    where
+        Self: Sized + Monoid,
+    {
+        let a = std::mem::replace(self, Self::empty());
+        *self = a.combine(b);
+    }
+
+    fn combine_all_option(iter: impl IntoIterator<Item = Self>) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        iter.into_iter().reduce(Self::combine)
+    }
+
+    /// Wraps `self` in the semigroup that combines in the opposite order.
+    fn dual(self) -> Dual<Self>
+    where
+        Self: Sized,
+    {
+        Dual(self)
+    }
 }
 
-impl<K: Eq + Hash, V> Semigroup for HashMap<K, V> {
+/// Combines `base` with itself `n >= 1` times by repeated squaring.
+pub(crate) fn repeated_squaring<S: Semigroup + Clone>(mut base: S, mut n: u64) -> S {
+    debug_assert!(n > 0, "repeated_squaring needs n >= 1");
+    while n & 1 == 0 {
+        base = base.clone().combine(base);
+        n >>= 1;
+    }
+    let mut acc = base.clone();
+    n >>= 1;
+    while n > 0 {
+        base = base.clone().combine(base);
+        if n & 1 == 1 {
+            acc = acc.combine(base.clone());
+        }
+        n >>= 1;
+    }
+    acc
+}
+
+/// Right-biased: on a key collision the value from `b` wins. Use
+/// [`MergeMap`](super::merge_map::MergeMap) to combine the values instead.
+impl<K: Eq + Hash, V, S: BuildHasher> Semigroup for HashMap<K, V, S> {
     fn combine(mut self, b: Self) -> Self {
         self.extend(b);
         self
     }
+
+    fn combine_assign(&mut self, b: Self) {
+        self.extend(b);
+    }
 }
 
-impl<A: Eq + Hash> Semigroup for HashSet<A> {
+impl<A: Eq + Hash, S: BuildHasher> Semigroup for HashSet<A, S> {
     fn combine(mut self, b: Self) -> Self {
         self.extend(b);
         self
     }
+
+    fn combine_assign(&mut self, b: Self) {
+        self.extend(b);
+    }
+}
+
+/// Right-biased like the `HashMap` instance.
+impl<K: Ord, V> Semigroup for BTreeMap<K, V> {
+    fn combine(mut self, mut b: Self) -> Self {
+        self.append(&mut b);
+        self
+    }
+
+    fn combine_assign(&mut self, mut b: Self) {
+        self.append(&mut b);
+    }
+}
+
+impl<A: Ord> Semigroup for BTreeSet<A> {
+    fn combine(mut self, mut b: Self) -> Self {
+        self.append(&mut b);
+        self
+    }
+
+    fn combine_assign(&mut self, mut b: Self) {
+        self.append(&mut b);
+    }
+}
+
+impl<A: Ord> Semigroup for BinaryHeap<A> {
+    fn combine(mut self, mut b: Self) -> Self {
+        self.append(&mut b);
+        self
+    }
+
+    fn combine_assign(&mut self, mut b: Self) {
+        self.append(&mut b);
+    }
 }
 
 impl<A> Semigroup for Vec<A> {
@@ -24,6 +145,69 @@ impl<A> Semigroup for Vec<A> {
         self.append(&mut b);
         self
     }
+
+    fn combine_assign(&mut self, mut b: Self) {
+        self.append(&mut b);
+    }
+
+    /// Sizes the result once, but still clones `b` whole; see
+    /// [`Semigroup::combine_ref`].
+    fn combine_ref(&self, b: &Self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut combined = Vec::with_capacity(self.len() + b.len());
+        combined.clone_from(self);
+        combined.append(&mut b.clone());
+        combined
+    }
+}
+
+impl<A> Semigroup for VecDeque<A> {
+    fn combine(mut self, mut b: Self) -> Self {
+        self.append(&mut b);
+        self
+    }
+
+    fn combine_assign(&mut self, mut b: Self) {
+        self.append(&mut b);
+    }
+}
+
+impl<A> Semigroup for LinkedList<A> {
+    fn combine(mut self, mut b: Self) -> Self {
+        self.append(&mut b);
+        self
+    }
+
+    fn combine_assign(&mut self, mut b: Self) {
+        self.append(&mut b);
+    }
+}
+
+impl<A: Semigroup, const N: usize> Semigroup for [A; N] {
+    fn combine(self, b: Self) -> Self {
+        let mut b = IntoIterator::into_iter(b);
+        self.map(|a| a.combine(b.next().expect("arrays have the same length")))
+    }
+}
+
+impl<A: Semigroup> Semigroup for Box<A> {
+    fn combine(self, b: Self) -> Self {
+        Box::new((*self).combine(*b))
+    }
+}
+
+impl<A: Semigroup + Clone> Semigroup for Rc<A> {
+    fn combine(self, b: Self) -> Self {
+        Rc::new(Rc::unwrap_or_clone(self).combine(Rc::unwrap_or_clone(b)))
+    }
+}
+
+impl<A: Semigroup + Clone> Semigroup for Arc<A> {
+    fn combine(self, b: Self) -> Self {
+        Arc::new(Arc::unwrap_or_clone(self).combine(Arc::unwrap_or_clone(b)))
+    }
 }
 
 impl<A: Semigroup> Semigroup for Option<A> {
@@ -52,6 +236,44 @@ impl Semigroup for String {
         self.push_str(&b);
         self
     }
+
+    fn combine_assign(&mut self, b: Self) {
+        self.push_str(&b);
+    }
+
+    fn combine_ref(&self, b: &Self) -> Self {
+        let mut combined = String::with_capacity(self.len() + b.len());
+        combined.push_str(self);
+        combined.push_str(b);
+        combined
+    }
+}
+
+impl Semigroup for Cow<'_, str> {
+    fn combine(self, b: Self) -> Self {
+        if str::is_empty(&self) {
+            b
+        } else if str::is_empty(&b) {
+            self
+        } else {
+            let mut owned = self.into_owned();
+            owned.push_str(&b);
+            Cow::Owned(owned)
+        }
+    }
+}
+
+impl Semigroup for Duration {
+    fn combine(self, b: Self) -> Self {
+        self.saturating_add(b)
+    }
+}
+
+/// Lexicographic: the first non-`Equal` ordering wins.
+impl Semigroup for Ordering {
+    fn combine(self, b: Self) -> Self {
+        self.then(b)
+    }
 }
 
 impl Semigroup for () {
@@ -79,12 +301,115 @@ impl Semigroup for f64 {
     }
 }
 
-impl_semigroup_wrapping!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+impl_semigroup_wrapping!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_semigroup_tuple {
+    ($(($($t:ident $i:tt),+)),*) => {$(
+        impl<$($t: Semigroup),+> Semigroup for ($($t,)+) {
+            fn combine(self, b: Self) -> Self {
+                ($(self.$i.combine(b.$i),)+)
+            }
+        }
+    )*};
+}
+
+impl_semigroup_tuple!(
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn times(n: u8) -> NonZeroUsize {
+        NonZeroUsize::new(n as usize + 1).unwrap()
+    }
+
+    #[quickcheck]
+    fn combine_n_nonzero_agrees_with_naive_fold(a: String, n: u8) -> bool {
+        let naive = (1..times(n).get()).fold(a.clone(), |acc, _| acc.combine(a.clone()));
+        a.combine_n_nonzero(times(n)) == naive
+    }
+
+    #[quickcheck]
+    fn combine_n_nonzero_agrees_with_wrapping_mul(a: u32, n: u32) -> bool {
+        match NonZeroUsize::new(n as usize) {
+            Some(times) => a.combine_n_nonzero(times) == a.wrapping_mul(n),
+            None => true,
+        }
+    }
+
+    #[quickcheck]
+    fn combine_all_option_agrees_with_naive_fold(xs: Vec<Vec<u8>>) -> bool {
+        let naive = xs
+            .iter()
+            .cloned()
+            .fold(None, |acc: Option<Vec<u8>>, x| match acc {
+                None => Some(x),
+                Some(acc) => Some(acc.combine(x)),
+            });
+        Semigroup::combine_all_option(xs) == naive
+    }
+
+    #[quickcheck]
+    fn combine_ref_agrees_with_combine(
+        s1: String,
+        s2: String,
+        v1: Vec<u8>,
+        v2: Vec<u8>,
+        m1: HashMap<u8, u8>,
+        m2: HashMap<u8, u8>,
+    ) -> bool {
+        s1.combine_ref(&s2) == s1.clone().combine(s2.clone())
+            && v1.combine_ref(&v2) == v1.clone().combine(v2.clone())
+            && m1.combine_ref(&m2) == m1.clone().combine(m2.clone())
+    }
+
+    #[quickcheck]
+    fn combine_assign_agrees_with_combine(
+        s1: String,
+        s2: String,
+        d1: VecDeque<u8>,
+        d2: VecDeque<u8>,
+        o1: Option<u16>,
+        o2: Option<u16>,
+    ) -> bool {
+        let (mut s, mut d, mut o) = (s1.clone(), d1.clone(), o1);
+        s.combine_assign(s2.clone());
+        d.combine_assign(d2.clone());
+        o.combine_assign(o2);
+        s == s1.combine(s2) && d == d1.combine(d2) && o == o1.combine(o2)
+    }
+
+    #[quickcheck]
+    fn dual_swaps_the_arguments(a: String, b: String) -> bool {
+        a.clone().dual().combine(b.clone().dual()).0 == b.combine(a)
+    }
+}
 
 #[cfg(test)]
 mod laws {
 
     macro_rules! semigroup_associativity {
+        (tuple $($t:ident),*) => {$(
+            paste::paste! {
+                semigroup_associativity!(
+                    [<associativity_ $t>]: $t,
+                    from crate::testing::ArbitraryTuple<$t>,
+                    |t: crate::testing::ArbitraryTuple<$t>| t.0
+                );
+            }
+        )*};
         ($($t:ty),*) => {$(
             paste::paste! {
                 semigroup_associativity!([<associativity_ $t>]: $t);
@@ -130,6 +455,47 @@ mod laws {
                 }
             }
         };
+        ($name:ident: $t:ty, approx $f:ident by $proj:expr) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t, n2: $t, n3: $t) -> bool {
+                use crate::kernel::semigroup::*;
+                use float_cmp::approx_eq;
+                let n1_copy = n1.clone();
+                let n2_copy = n2.clone();
+                let n3_copy = n3.clone();
+
+                let left = ($proj)(n1.combine(n2.combine(n3)));
+                let right = ($proj)(n1_copy.combine(n2_copy).combine(n3_copy));
+
+                approx_eq!($f, left, right)
+            }
+        };
+        ($name:ident: $t:ty, by $f:expr) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(n1: $t, n2: $t, n3: $t) -> bool {
+                use crate::kernel::semigroup::*;
+                let n1_copy = n1.clone();
+                let n2_copy = n2.clone();
+                let n3_copy = n3.clone();
+
+                ($f)(n1.combine(n2.combine(n3))) == ($f)(n1_copy.combine(n2_copy).combine(n3_copy))
+            }
+        };
+        ($name:ident: $t:ty, from $s:ty, $f:expr) => {
+            #[allow(non_snake_case)]
+            #[quickcheck]
+            fn $name(s1: $s, s2: $s, s3: $s) -> bool {
+                use crate::kernel::semigroup::*;
+                let (n1, n2, n3): ($t, $t, $t) = (($f)(s1), ($f)(s2), ($f)(s3));
+                let n1_copy = n1.clone();
+                let n2_copy = n2.clone();
+                let n3_copy = n3.clone();
+
+                n1.combine(n2.combine(n3)) == n1_copy.combine(n2_copy).combine(n3_copy)
+            }
+        };
         ($name:ident: $t:ty) => {
             #[allow(non_snake_case)]
             #[quickcheck]
@@ -145,7 +511,18 @@ mod laws {
     }
 
     mod associativity {
-        use std::collections::{HashMap, HashSet};
+        use crate::kernel::checked::Checked;
+        use crate::kernel::kahan::KahanSum;
+        use crate::kernel::merge_map::MergeMap;
+        use crate::kernel::saturating::Saturating;
+        use crate::kernel::wrappers::*;
+        use std::borrow::Cow;
+        use std::collections::{
+            BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque,
+        };
+        use std::rc::Rc;
+        use std::sync::Arc;
+        use std::time::Duration;
 
         type VecUsize = Vec<usize>;
         type SetUsize = HashSet<usize>;
@@ -153,18 +530,169 @@ mod laws {
         type OptionUsize = Option<usize>;
         type ResultUsizeUsize = Result<usize, usize>;
         type Unit = ();
+        type SumU8 = Sum<u8>;
+        type ProductI32 = Product<i32>;
+        type MinI64 = Min<i64>;
+        type MaxString = Max<String>;
+        type FirstString = First<String>;
+        type LastString = Last<String>;
+        type DualString = Dual<String>;
+        type MergeHashMapUsizeString = MergeMap<HashMap<usize, String>>;
+        type MergeBTreeMapU8VecU8 = MergeMap<BTreeMap<u8, Vec<u8>>>;
+        type VecDequeU8 = VecDeque<u8>;
+        type LinkedListU8 = LinkedList<u8>;
+        type BTreeMapU8String = BTreeMap<u8, String>;
+        type BTreeSetU8 = BTreeSet<u8>;
+        type BinaryHeapU8 = BinaryHeap<u8>;
+        type BoxString = Box<String>;
+        type RcString = Rc<String>;
+        type ArcString = Arc<String>;
+        type CowStr = Cow<'static, str>;
+        type ArrayString3 = [String; 3];
+        type CheckedSumU8 = Checked<Sum<u8>>;
+        type CheckedSumU16 = Checked<Sum<u16>>;
+        type CheckedSumU32 = Checked<Sum<u32>>;
+        type CheckedSumU64 = Checked<Sum<u64>>;
+        type CheckedSumU128 = Checked<Sum<u128>>;
+        type CheckedSumUsize = Checked<Sum<usize>>;
+        type SaturatingSumU8 = Saturating<Sum<u8>>;
+        type SaturatingSumU16 = Saturating<Sum<u16>>;
+        type SaturatingSumU32 = Saturating<Sum<u32>>;
+        type SaturatingSumU64 = Saturating<Sum<u64>>;
+        type SaturatingSumU128 = Saturating<Sum<u128>>;
+        type SaturatingSumUsize = Saturating<Sum<usize>>;
+        type SaturatingProductU8 = Saturating<Product<u8>>;
+        type SaturatingProductU16 = Saturating<Product<u16>>;
+        type SaturatingProductU32 = Saturating<Product<u32>>;
+        type SaturatingProductU64 = Saturating<Product<u64>>;
+        type SaturatingProductU128 = Saturating<Product<u128>>;
+        type SaturatingProductUsize = Saturating<Product<usize>>;
+        type EitherStringUsize = crate::data::either::Either<String, usize>;
+        type IorStringString = crate::data::ior::Ior<String, String>;
+        type NonEmptyVecUsize = crate::data::non_empty_vec::NonEmptyVec<usize>;
+        type Tuple2 = (usize, String);
+        type Tuple3 = (Vec<u8>, Option<u16>, i32);
+        type Tuple4 = (String, Vec<u8>, Option<u16>, Unit);
+        type Tuple5 = (u8, String, Min<i64>, Unit, HashSet<u8>);
+        type Tuple6 = (u8, String, Vec<u8>, Option<u16>, Max<u32>, Unit);
+        type Tuple7 = (u8, String, Vec<u8>, Option<u16>, Max<u32>, Unit, All);
+        type Tuple8 = (u8, u16, u32, u64, i8, i16, i32, i64);
+        type Tuple9 = (u8, u16, u32, u64, i8, i16, i32, i64, String);
+        type Tuple10 = (u8, u16, u32, u64, i8, i16, i32, i64, String, Vec<u8>);
+        type Tuple11 = (
+            u8,
+            u16,
+            u32,
+            u64,
+            i8,
+            i16,
+            i32,
+            i64,
+            String,
+            Vec<u8>,
+            Option<u16>,
+        );
+        type Tuple12 = (
+            u8,
+            u16,
+            u32,
+            u64,
+            i8,
+            i16,
+            i32,
+            i64,
+            String,
+            Vec<u8>,
+            Option<u16>,
+            Any,
+        );
+        semigroup_associativity!(
+            tuple Tuple2,
+            Tuple3,
+            Tuple4,
+            Tuple5,
+            Tuple6,
+            Tuple7,
+            Tuple8,
+            Tuple9,
+            Tuple10,
+            Tuple11,
+            Tuple12
+        );
+        semigroup_associativity!(
+            associativity_KahanSumF32: KahanSum<f32>,
+            approx f32 by |k: KahanSum<f32>| k.total()
+        );
+        semigroup_associativity!(
+            associativity_KahanSumF64: KahanSum<f64>,
+            approx f64 by |k: KahanSum<f64>| k.total()
+        );
+        semigroup_associativity!(
+            associativity_BinaryHeapU8: BinaryHeapU8,
+            by BinaryHeap::into_sorted_vec
+        );
+        semigroup_associativity!(associativity_RcString: RcString, from String, Rc::new);
+        semigroup_associativity!(associativity_CowStr: CowStr, from String, Cow::Owned);
+        semigroup_associativity!(
+            associativity_Ordering: std::cmp::Ordering,
+            from i8,
+            |n: i8| n.cmp(&0)
+        );
         semigroup_associativity!(
             usize,
             u64,
             String,
-            // f32, TODO handle this
-            // f64, TODO handle this
+            // f32 and f64 addition is not associative, see the KahanSum
+            // instances above
             Unit,
             VecUsize,
             SetUsize,
             HashMapUsizeUsize,
             OptionUsize,
-            ResultUsizeUsize
+            ResultUsizeUsize,
+            SumU8,
+            ProductI32,
+            MinI64,
+            MaxString,
+            FirstString,
+            LastString,
+            All,
+            Any,
+            DualString,
+            MergeHashMapUsizeString,
+            MergeBTreeMapU8VecU8,
+            i128,
+            u128,
+            isize,
+            Duration,
+            VecDequeU8,
+            LinkedListU8,
+            BTreeMapU8String,
+            BTreeSetU8,
+            BoxString,
+            ArcString,
+            ArrayString3,
+            CheckedSumU8,
+            CheckedSumU16,
+            CheckedSumU32,
+            CheckedSumU64,
+            CheckedSumU128,
+            CheckedSumUsize,
+            SaturatingSumU8,
+            SaturatingSumU16,
+            SaturatingSumU32,
+            SaturatingSumU64,
+            SaturatingSumU128,
+            SaturatingSumUsize,
+            SaturatingProductU8,
+            SaturatingProductU16,
+            SaturatingProductU32,
+            SaturatingProductU64,
+            SaturatingProductU128,
+            SaturatingProductUsize,
+            EitherStringUsize,
+            IorStringString,
+            NonEmptyVecUsize
         );
     }
 }
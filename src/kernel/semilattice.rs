@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+use super::band::Band;
+use super::commutative::{CommutativeMonoid, CommutativeSemigroup};
+use super::monoid::Monoid;
+use super::wrappers::{All, Any, Max, Min};
+
+/// A commutative band; `combine` behaves like a join (or meet) of a lattice.
+pub trait Semilattice: Band + CommutativeSemigroup {}
+
+pub trait BoundedSemilattice: Semilattice + CommutativeMonoid {}
+
+impl Semilattice for () {}
+impl BoundedSemilattice for () {}
+
+impl<A: Eq + Hash, S: BuildHasher> Semilattice for HashSet<A, S> {}
+impl<A: Eq + Hash, S: BuildHasher + Default> BoundedSemilattice for HashSet<A, S> {}
+
+impl<A: Semilattice> Semilattice for Option<A> {}
+impl<A: Semilattice> BoundedSemilattice for Option<A> {}
+
+impl Semilattice for All {}
+impl BoundedSemilattice for All {}
+impl Semilattice for Any {}
+impl BoundedSemilattice for Any {}
+impl<A> Semilattice for Min<A> where Self: Band + CommutativeSemigroup {}
+impl<A> BoundedSemilattice for Min<A> where Self: Semilattice + Monoid {}
+impl<A> Semilattice for Max<A> where Self: Band + CommutativeSemigroup {}
+impl<A> BoundedSemilattice for Max<A> where Self: Semilattice + Monoid {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::prelude::*;
+
+    fn join_all<A: BoundedSemilattice>(values: Vec<A>) -> A {
+        values.into_iter().fold(A::empty(), A::combine)
+    }
+
+    #[test]
+    fn joins_ignore_order_and_duplicates() {
+        let forward = join_all(vec![Max(3u8), Max(9), Max(3), Max(1)]);
+        let backward = join_all(vec![Max(1u8), Max(3), Max(9), Max(9)]);
+        assert_eq!(forward, backward);
+        assert_eq!(forward, Max(9));
+    }
+}
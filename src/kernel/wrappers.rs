@@ -0,0 +1,323 @@
+use std::cmp::Ordering;
+
+use super::monoid::Monoid;
+use super::order::Order;
+use super::semigroup::Semigroup;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sum<A>(pub A);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Product<A>(pub A);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Min<A>(pub A);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Max<A>(pub A);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct First<A>(pub A);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Last<A>(pub A);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct All(pub bool);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Any(pub bool);
+
+/// Combines in the opposite order of the wrapped semigroup.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dual<A>(pub A);
+
+macro_rules! impl_sum_product_wrapping {
+    ($($t:ty),*) => {$(
+        impl Semigroup for Sum<$t> {
+            fn combine(self, b: Self) -> Self {
+                Sum(self.0.wrapping_add(b.0))
+            }
+        }
+
+        impl Monoid for Sum<$t> {
+            fn empty() -> Self {
+                Sum(0)
+            }
+        }
+
+        impl Semigroup for Product<$t> {
+            fn combine(self, b: Self) -> Self {
+                Product(self.0.wrapping_mul(b.0))
+            }
+        }
+
+        impl Monoid for Product<$t> {
+            fn empty() -> Self {
+                Product(1)
+            }
+        }
+    )*};
+}
+
+impl_sum_product_wrapping!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_sum_product_float {
+    ($($t:ty),*) => {$(
+        impl Semigroup for Sum<$t> {
+            fn combine(self, b: Self) -> Self {
+                Sum(self.0 + b.0)
+            }
+        }
+
+        impl Monoid for Sum<$t> {
+            fn empty() -> Self {
+                Sum(0.0)
+            }
+        }
+
+        impl Semigroup for Product<$t> {
+            fn combine(self, b: Self) -> Self {
+                Product(self.0 * b.0)
+            }
+        }
+
+        impl Monoid for Product<$t> {
+            fn empty() -> Self {
+                Product(1.0)
+            }
+        }
+    )*};
+}
+
+impl_sum_product_float!(f32, f64);
+
+impl<A: Order> Semigroup for Min<A> {
+    fn combine(self, b: Self) -> Self {
+        Min(Order::min(self.0, b.0))
+    }
+}
+
+impl<A: Order> Min<A> {
+    /// `combine` in place, for the orders with no greatest element to
+    /// serve as `Monoid::empty`.
+    pub fn combine_assign(&mut self, b: Self) {
+        if b.0.compare(&self.0) == Ordering::Less {
+            *self = b;
+        }
+    }
+}
+
+impl<A: Order> Semigroup for Max<A> {
+    fn combine(self, b: Self) -> Self {
+        Max(Order::max(self.0, b.0))
+    }
+}
+
+impl<A: Order> Max<A> {
+    /// `combine` in place, for the orders with no least element to serve as
+    /// `Monoid::empty`.
+    pub fn combine_assign(&mut self, b: Self) {
+        if self.0.compare(&b.0) == Ordering::Less {
+            *self = b;
+        }
+    }
+}
+
+macro_rules! impl_min_max_bounded {
+    ($($t:ty),*) => {$(
+        impl Monoid for Min<$t> {
+            fn empty() -> Self {
+                Min(<$t>::MAX)
+            }
+        }
+
+        impl Monoid for Max<$t> {
+            fn empty() -> Self {
+                Max(<$t>::MIN)
+            }
+        }
+    )*};
+}
+
+impl_min_max_bounded!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, char);
+
+impl Monoid for Min<bool> {
+    fn empty() -> Self {
+        Min(true)
+    }
+}
+
+impl Monoid for Max<bool> {
+    fn empty() -> Self {
+        Max(false)
+    }
+}
+
+// These have a least element but no greatest one, so only Max is a monoid.
+impl Monoid for Max<String> {
+    fn empty() -> Self {
+        Max(String::new())
+    }
+}
+
+impl<A: Order> Monoid for Max<Vec<A>> {
+    fn empty() -> Self {
+        Max(Vec::new())
+    }
+}
+
+impl<A: Order> Monoid for Max<Option<A>> {
+    fn empty() -> Self {
+        Max(None)
+    }
+}
+
+impl<A> Semigroup for First<A> {
+    fn combine(self, _: Self) -> Self {
+        self
+    }
+}
+
+impl<A> First<A> {
+    /// `combine` in place; `First` has no `Monoid::empty`.
+    pub fn combine_assign(&mut self, _: Self) {}
+}
+
+impl<A> Semigroup for Last<A> {
+    fn combine(self, b: Self) -> Self {
+        b
+    }
+}
+
+impl<A> Last<A> {
+    /// `combine` in place; `Last` has no `Monoid::empty`.
+    pub fn combine_assign(&mut self, b: Self) {
+        *self = b;
+    }
+}
+
+impl Semigroup for All {
+    fn combine(self, b: Self) -> Self {
+        All(self.0 && b.0)
+    }
+}
+
+impl Monoid for All {
+    fn empty() -> Self {
+        All(true)
+    }
+}
+
+impl Semigroup for Any {
+    fn combine(self, b: Self) -> Self {
+        Any(self.0 || b.0)
+    }
+}
+
+impl Monoid for Any {
+    fn empty() -> Self {
+        Any(false)
+    }
+}
+
+impl<A: Semigroup> Semigroup for Dual<A> {
+    fn combine(self, b: Self) -> Self {
+        Dual(b.0.combine(self.0))
+    }
+}
+
+impl<A: Monoid> Monoid for Dual<A> {
+    fn empty() -> Self {
+        Dual(A::empty())
+    }
+}
+
+#[cfg(test)]
+macro_rules! impl_arbitrary_wrapper {
+    ($($w:ident<$a:ident>),*) => {$(
+        impl<$a: quickcheck::Arbitrary> quickcheck::Arbitrary for $w<$a> {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                $w($a::arbitrary(g))
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(self.0.shrink().map($w))
+            }
+        }
+    )*};
+    ($($w:ident),*) => {$(
+        impl quickcheck::Arbitrary for $w {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                $w(bool::arbitrary(g))
+            }
+        }
+    )*};
+}
+
+#[cfg(test)]
+impl_arbitrary_wrapper!(
+    Sum<A>,
+    Product<A>,
+    Min<A>,
+    Max<A>,
+    First<A>,
+    Last<A>,
+    Dual<A>
+);
+
+#[cfg(test)]
+impl_arbitrary_wrapper!(All, Any);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::prelude::*;
+    use crate::data::prelude::*;
+
+    #[test]
+    fn fold_map_with_wrappers() {
+        let values = vec![3, 1, 4, 1, 5];
+        assert_eq!(values.clone().fold_map(Max), Max(5));
+        assert_eq!(values.clone().fold_map(Min), Min(1));
+        assert_eq!(values.clone().fold_map(Sum), Sum(14));
+        assert_eq!(values.clone().fold_map(Product), Product(60));
+        assert_eq!(values.clone().fold_map(|x| All(x > 0)), All(true));
+        assert_eq!(values.fold_map(|x| Any(x > 4)), Any(true));
+    }
+
+    #[test]
+    fn fold_map_of_empty_is_the_identity() {
+        assert_eq!(Vec::<i32>::new().fold_map(Max), Max(i32::MIN));
+        assert_eq!(Vec::<u8>::new().fold_map(Product), Product(1));
+        assert_eq!(None.fold_map(|x: bool| All(x)), All(true));
+    }
+
+    #[test]
+    fn reduce_map_with_semigroup_only_wrappers() {
+        let values = NonEmptyVec::new(2.5, vec![-1.0, 7.25]);
+        assert_eq!(values.clone().reduce_map(First), First(2.5));
+        assert_eq!(values.clone().reduce_map(Last), Last(7.25));
+        assert_eq!(values.reduce_map(Max), Max(7.25));
+    }
+
+    #[quickcheck]
+    fn combine_assign_without_a_monoid(a: Min<String>, b: Max<String>, c: (u8, u8)) -> bool {
+        let (mut min, mut max) = (a.clone(), b.clone());
+        min.combine_assign(Min("m".to_string()));
+        max.combine_assign(Max("m".to_string()));
+        let (mut first, mut last) = (First(c.0), Last(c.0));
+        first.combine_assign(First(c.1));
+        last.combine_assign(Last(c.1));
+        min == a.combine(Min("m".to_string()))
+            && max == b.combine(Max("m".to_string()))
+            && first == First(c.0)
+            && last == Last(c.1)
+    }
+
+    #[test]
+    fn dual_reverses_the_order() {
+        let combined = Dual("a".to_string()).combine(Dual("b".to_string()));
+        assert_eq!(combined, Dual("ba".to_string()));
+    }
+}
@@ -8,5 +8,10 @@ extern crate quickcheck;
 #[macro_use(quickcheck)]
 extern crate quickcheck_macros;
 
+#[cfg(test)]
+#[macro_use]
+mod testing;
+
 pub mod core;
+pub mod data;
 pub mod kernel;
@@ -0,0 +1,397 @@
+//! Fixtures and law macros shared by the type class law tests.
+
+use crate::core::prelude::Functor;
+
+pub(crate) type Function = fn(usize) -> usize;
+
+pub(crate) type Lifted<F, B> = <F as Functor>::Outter<B>;
+
+pub(crate) const FUNCTIONS: [Function; 4] = [
+    |a| a / 2,
+    |a| a.wrapping_mul(3),
+    |a| a.wrapping_add(7),
+    |a| a % 5,
+];
+
+pub(crate) fn function(picked: u8) -> Function {
+    FUNCTIONS[picked as usize % FUNCTIONS.len()]
+}
+
+/// Builds a Kleisli arrow from one of `FUNCTIONS`; `lift` receives the input
+/// and the mapped value and decides which effect the arrow produces.
+pub(crate) fn kleisli<F>(picked: u8, lift: fn(usize, usize) -> F) -> impl Fn(usize) -> F {
+    let f = function(picked);
+    move |a| lift(a, f(a))
+}
+
+macro_rules! functor_identity {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            functor_identity!([<functor_identity_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t) -> bool {
+            use crate::core::prelude::*;
+
+            fa.clone().fmap(|a| a) == fa
+        }
+    };
+}
+
+macro_rules! apply_composition {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            apply_composition!([<apply_composition_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(
+            fa: $t,
+            fab: crate::testing::Lifted<$t, u8>,
+            fbc: crate::testing::Lifted<$t, u8>,
+        ) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::{function, Function};
+            let (fab, fbc) = (fab.fmap(function), fbc.fmap(function));
+            let compose = |bc: Function| move |ab: Function| move |a| bc(ab(a));
+
+            let left = fa
+                .clone()
+                .apply(fab.clone().apply(fbc.clone().fmap(compose)));
+            let right = fa.apply(fab).apply(fbc);
+
+            left == right
+        }
+    };
+}
+
+macro_rules! apply_consistent_with_fmap {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            apply_consistent_with_fmap!([<apply_consistent_with_fmap_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t, f: u8) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::{function, Function, Lifted};
+            let f = function(f);
+
+            fa.clone().apply(<Lifted<$t, Function>>::pure(f)) == fa.fmap(f)
+        }
+    };
+}
+
+macro_rules! applicative_identity {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            applicative_identity!([<applicative_identity_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::{Function, Lifted};
+
+            fa.clone().apply(<Lifted<$t, Function>>::pure(|a| a)) == fa
+        }
+    };
+}
+
+macro_rules! functor_composition {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            functor_composition!([<functor_composition_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t, f: u8, g: u8) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::function;
+            let (f, g) = (function(f), function(g));
+
+            fa.clone().fmap(f).fmap(g) == fa.fmap(|a| g(f(a)))
+        }
+    };
+}
+
+macro_rules! applicative_homomorphism {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            applicative_homomorphism!([<applicative_homomorphism_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(a: usize, f: u8) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::{function, Function, Lifted};
+            let f = function(f);
+
+            <$t>::pure(a).apply(<Lifted<$t, Function>>::pure(f)) == <$t>::pure(f(a))
+        }
+    };
+}
+
+macro_rules! flat_map_associativity {
+    ($($t:ident by $lift:expr),*) => {$(
+        paste::paste! {
+            flat_map_associativity!([<flat_map_associativity_ $t>]: $t, by $lift);
+        }
+    )*};
+    ($name:ident: $t:ty, by $lift:expr) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t, f: u8, g: u8) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::kleisli;
+            let (f, g) = (kleisli(f, $lift), kleisli(g, $lift));
+
+            fa.clone().flat_map(&f).flat_map(&g) == fa.flat_map(|a| f(a).flat_map(&g))
+        }
+    };
+}
+
+macro_rules! monad_left_identity {
+    ($($t:ident by $lift:expr),*) => {$(
+        paste::paste! {
+            monad_left_identity!([<monad_left_identity_ $t>]: $t, by $lift);
+        }
+    )*};
+    ($name:ident: $t:ty, by $lift:expr) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(a: usize, f: u8) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::kleisli;
+            let f = kleisli(f, $lift);
+
+            <$t>::pure(a).flat_map(&f) == f(a)
+        }
+    };
+}
+
+macro_rules! monad_right_identity {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            monad_right_identity!([<monad_right_identity_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t) -> bool {
+            use crate::core::prelude::*;
+
+            fa.clone().flat_map(<$t>::pure) == fa
+        }
+    };
+}
+
+macro_rules! bifunctor_identity {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            bifunctor_identity!([<bifunctor_identity_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t) -> bool {
+            use crate::core::prelude::*;
+
+            fa.clone().bimap(|l| l, |r| r) == fa
+        }
+    };
+}
+
+macro_rules! bifunctor_composition {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            bifunctor_composition!([<bifunctor_composition_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t, f: u8, g: u8) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::function;
+            let (f, g) = (function(f), function(g));
+
+            let left = fa.clone().bimap(|l: String| l.len(), f).bimap(|l| l * 2, g);
+            let right = fa.bimap(|l: String| l.len() * 2, |r| g(f(r)));
+            left == right
+        }
+    };
+}
+
+macro_rules! foldable_consistent_with_right {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            foldable_consistent_with_right!([<foldable_consistent_with_right_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t) -> bool {
+            use crate::core::prelude::*;
+            let expected = fa.clone().right().map_or(0, |r| r / 2);
+
+            let left = fa.clone().fold_left(0, |acc, r| acc + r / 2);
+            let right = fa.fold_right(0, |acc, r| acc + r / 2);
+            left == expected && right == expected
+        }
+    };
+}
+
+macro_rules! reduce_left_to_consistent_with_fold_left {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            reduce_left_to_consistent_with_fold_left!(
+                [<reduce_left_to_consistent_with_fold_left_ $t>]: $t
+            );
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t, f: u8) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::function;
+            let f = function(f);
+            let push = |mut acc: Vec<usize>, a| {
+                acc.push(f(a));
+                acc
+            };
+
+            fa.clone().reduce_left_to(|a| vec![f(a)], push) == fa.fold_left(Vec::new(), push)
+        }
+    };
+}
+
+macro_rules! reduce_right_to_consistent_with_fold_right {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            reduce_right_to_consistent_with_fold_right!(
+                [<reduce_right_to_consistent_with_fold_right_ $t>]: $t
+            );
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t, f: u8) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::function;
+            let f = function(f);
+            let push = |mut acc: Vec<usize>, a| {
+                acc.push(f(a));
+                acc
+            };
+
+            fa.clone().reduce_right_to(|a| vec![f(a)], push) == fa.fold_right(Vec::new(), push)
+        }
+    };
+}
+
+macro_rules! reduce_map_consistent_with_fold_map {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            reduce_map_consistent_with_fold_map!([<reduce_map_consistent_with_fold_map_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t, f: u8) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::function;
+            let f = function(f);
+
+            fa.clone().reduce_map(|a| vec![f(a)]) == fa.fold_map(|a| vec![f(a)])
+        }
+    };
+}
+
+macro_rules! semigroup_k_associativity {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            semigroup_k_associativity!([<semigroup_k_associativity_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(a: $t, b: $t, c: $t) -> bool {
+            use crate::core::prelude::*;
+
+            let left = a.clone().combine_k(b.clone().combine_k(c.clone()));
+            let right = a.combine_k(b).combine_k(c);
+            left == right
+        }
+    };
+}
+
+macro_rules! apply_consistent_with_flat_map {
+    ($($t:ident),*) => {$(
+        paste::paste! {
+            apply_consistent_with_flat_map!([<apply_consistent_with_flat_map_ $t>]: $t);
+        }
+    )*};
+    ($name:ident: $t:ty) => {
+        #[allow(non_snake_case)]
+        #[quickcheck]
+        fn $name(fa: $t, fab: crate::testing::Lifted<$t, u8>) -> bool {
+            use crate::core::prelude::*;
+            use crate::testing::function;
+            let fab = fab.fmap(function);
+
+            let left = fa.clone().apply(fab.clone());
+            let right = fab.flat_map(|f| fa.clone().fmap(f));
+            left == right
+        }
+    };
+}
+
+/// Generates tuples of every arity the kernel implements, since quickcheck
+/// stops at arity 8.
+#[derive(Clone, Debug)]
+pub(crate) struct ArbitraryTuple<T>(pub(crate) T);
+
+macro_rules! impl_arbitrary_tuple {
+    ($(($($t:ident),+)),*) => {$(
+        impl<$($t: quickcheck::Arbitrary),+> quickcheck::Arbitrary for ArbitraryTuple<($($t,)+)> {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                ArbitraryTuple(($($t::arbitrary(g),)+))
+            }
+        }
+    )*};
+}
+
+impl_arbitrary_tuple!(
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+    (A, B, C, D, E, F, G, H, I),
+    (A, B, C, D, E, F, G, H, I, J),
+    (A, B, C, D, E, F, G, H, I, J, K),
+    (A, B, C, D, E, F, G, H, I, J, K, L)
+);